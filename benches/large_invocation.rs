@@ -0,0 +1,80 @@
+//! Measures the time to compile a crate with a single `trait_set!` invocation
+//! declaring a lot of aliases, e.g. generated from a schema.
+//!
+//! Run with `cargo bench --bench large_invocation`, the number of aliases
+//! can be set via `ALIASES` environment variable.
+
+use std::{fmt::Write, fs, path::Path, time::Instant};
+
+const DEFAULT_ALIASES: usize = 1500;
+
+/// Generates an invocation where aliases refer to the previous ones,
+/// so that the checks looking through aliases are exercised as well.
+/// Aliases with `i % 3 == 1` are generic.
+fn generate(aliases: usize) -> String {
+    // The closest previous non-generic and generic aliases.
+    let plain = |i: usize| if i % 3 == 1 { i - 1 } else { i };
+    let generic = |i: usize| i - (i + 2) % 3;
+
+    let mut source = String::from(
+        "#![deny(missing_docs)]\n\
+         //! Generated crate.\n\n\
+         use trait_set::trait_set;\n\n\
+         trait_set! {\n    \
+         #![trait_set(missing_docs = \"generate\", max_bounds = 16)]\n    \
+         #![allow(trait_set::too_many_bounds)]\n    \
+         use std::fmt::Debug;\n\n    \
+         pub trait Alias0 = Send + Sync;\n    \
+         pub trait Alias1<T> = AsRef<T>;\n",
+    );
+    for i in 2..aliases {
+        let _ = match i % 3 {
+            0 => writeln!(
+                source,
+                "    #[trait_set(bounds_str = \"ALIAS{}\")]\n    pub trait Alias{} = Alias{} + Debug;",
+                i,
+                i,
+                plain(i / 2),
+            ),
+            1 => writeln!(
+                source,
+                "    /// Alias number {}.\n    pub trait Alias{}<T: Clone> = Alias{} + Alias1<T>;",
+                i,
+                i,
+                plain(i / 2),
+            ),
+            _ => writeln!(
+                source,
+                "    pub(crate) trait Alias{} = Alias{}<u8> + Alias{} + 'static;",
+                i,
+                generic(i / 2),
+                plain(i / 3),
+            ),
+        };
+    }
+    source.push_str("}\n\nfn main() {}\n");
+    source
+}
+
+fn main() {
+    let aliases = std::env::var("ALIASES")
+        .ok()
+        .and_then(|aliases| aliases.parse().ok())
+        .unwrap_or(DEFAULT_ALIASES);
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("large_invocation.rs");
+    fs::write(&path, generate(aliases)).unwrap();
+
+    // Dependencies are built on the first run, so it's not measured.
+    let warmup = Path::new(env!("CARGO_TARGET_TMPDIR")).join("small_invocation.rs");
+    fs::write(&warmup, generate(2)).unwrap();
+    trybuild::TestCases::new().pass(&warmup);
+
+    let start = Instant::now();
+    // Test cases are compiled when dropped.
+    trybuild::TestCases::new().pass(&path);
+    println!(
+        "Compiled an invocation with {} aliases in {:?}",
+        aliases,
+        start.elapsed()
+    );
+}
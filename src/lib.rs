@@ -23,249 +23,5063 @@
 //!
 //! For more details, see the [`trait_set`] macro documentation.
 //!
+//! ## Cargo features
+//!
+//! - `extras` (enabled by default): doc-comment generation, `bounds_str` option,
+//!   `use` declarations inside the invocation and the validation passes (warnings,
+//!   `forbid`, `require_docs`, etc). Disable default features to build only the core
+//!   parsing and rendering without `full`, `visit` and `visit-mut` features of `syn`,
+//!   e.g. to reduce build time in large workspaces. In that case
+//!   `missing_docs = "generate"` falls back to allowing the lint.
+//! - `diagnostics-file`: if `TRAIT_SET_DIAGNOSTICS_FILE` environment variable is set
+//!   at build time, errors and warnings of the macro are also appended to that file
+//!   as JSON lines with `level`, `code`, `message`, `suggestion`, `file`, `line` and
+//!   `column` fields, e.g. to be collected by code quality tools. Locations require
+//!   Rust 1.88 or newer.
+//! - `nightly`: support for features of the nightly compiler. Const trait aliases, e.g.
+//!   `pub const trait Number = Zero + One + Copy;`, are rendered as const traits with
+//!   a const blanket impl, and can be used as `[const] Number` bounds in `const fn`.
+//!   Trait bounds of such aliases must be const traits, except for marker traits
+//!   (`Copy`, `Send`, `Sized`, `Sync` and `Unpin`). Requires `#![feature(const_trait_impl)]`
+//!   in the crate declaring the aliases.
+//! - `nightly-const`: support for const bounds in aliases, written either as `~const Trait`
+//!   or as `[const] Trait`, e.g. `pub trait ConstOps = ~const Add<Output = Self> + Copy;`.
+//!   Aliases with such bounds are const trait aliases where only the marked bounds are
+//!   const, in both the trait and the blanket impl. Implies `nightly`.
+//! - `trait-alias`: aliases are rendered as native trait aliases of the nightly compiler,
+//!   e.g. `pub trait ThreadSafe = Send + Sync;`, instead of a trait with a blanket impl,
+//!   for the exact semantics and diagnostics of trait aliases. Requires
+//!   `#![feature(trait_alias)]` in the crate declaring the aliases. Const trait aliases
+//!   are still rendered as with `nightly` feature.
+//! - `do-not-recommend`: blanket impls of aliases are marked with
+//!   `#[diagnostic::do_not_recommend]` (requires Rust 1.85 or newer), so that errors
+//!   say that the alias is not implemented instead of going through the blanket impl
+//!   to the first unsatisfied bound. Combine it with `on_unimplemented` option of
+//!   [`trait_set`] to list the alias bounds in such errors.
+//! - `static-assertions`: `#![require(...)]` and `#[trait_set::require(...)]` checks are
+//!   rendered via [`static_assertions`] macros (`assert_trait_sub_all!` and `assert_impl_all!`),
+//!   so that their failures look the same as the assertions written by hand. The crate using
+//!   the macros must depend on `static_assertions`. Checks these macros can't express, e.g.
+//!   for generic aliases and types or for lifetime bounds, are rendered as usual.
+//!
+//! ## Error codes
+//!
+//! Errors reported by the macro start with a stable code, e.g.
+//! `TS0003: Where clause is not allowed for trait alias`, explained below.
+//! Errors of the underlying parser (e.g. ``expected `=` ``) have no code.
+//!
+//! ### TS0001
+//!
+//! Only trait alias declarations are allowed inside `trait_set!`:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub struct Marker;
+//! }
+//! ```
+//!
+//! Declare other items outside of the macro.
+//!
+//! ### TS0002
+//!
+//! `use` declarations must go before all the aliases of the invocation:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Printable = Debug;
+//!     use std::fmt::Debug;
+//! }
+//! ```
+//!
+//! Move the declaration to the top of the invocation.
+//!
+//! ### TS0003
+//!
+//! An alias has where clauses both before `=` and after the bounds:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Printable<T> where T: Copy = AsRef<T> where T: Send;
+//! }
+//! ```
+//!
+//! Merge them into one: `pub trait Printable<T> = AsRef<T> where T: Copy + Send;`.
+//!
+//! ### TS0004
+//!
+//! Bounds of an alias are traits, not `impl Trait` types:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait ThreadSafe = impl Send + Sync;
+//! }
+//! ```
+//!
+//! Remove the `impl` keyword: `pub trait ThreadSafe = Send + Sync;`.
+//!
+//! ### TS0005
+//!
+//! Bounds of an alias are separated with `+`, not with commas:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait ThreadSafe = Send, Sync;
+//! }
+//! ```
+//!
+//! Use `+` instead: `pub trait ThreadSafe = Send + Sync;`.
+//!
+//! ### TS0006
+//!
+//! Aliases must be separated with `;`:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait ThreadSafe = Send + Sync
+//!     pub trait Printable = std::fmt::Debug;
+//! }
+//! ```
+//!
+//! Add the missing `;` after the alias.
+//!
+//! ### TS0007
+//!
+//! Attributes and doc-comments must be followed by an alias they are attached to:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait ThreadSafe = Send + Sync;
+//!     /// Printable types.
+//! }
+//! ```
+//!
+//! Remove them or add the missing alias.
+//!
+//! ### TS0008
+//!
+//! An alias can't refer to itself in its bounds. If the alias has the same name as the
+//! trait it's built from, the bound refers to the alias rather than to the trait:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Iterator = Iterator<Item = u8>;
+//! }
+//! ```
+//!
+//! Use the full path of the trait, e.g. `std::iter::Iterator<Item = u8>`, or rename
+//! the alias, e.g. to `ByteIterator`.
+//!
+//! ### TS0009
+//!
+//! Generic parameters of an alias must have unique names:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Pair<T, T> = AsRef<T>;
+//! }
+//! ```
+//!
+//! Rename or remove the duplicate parameter.
+//!
+//! ### TS0010
+//!
+//! `use` declarations inside the macro can't have attributes:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #[allow(unused_imports)]
+//!     use std::fmt::Debug;
+//!     pub trait Printable = Debug;
+//! }
+//! ```
+//!
+//! Remove the attribute or move the declaration out of the macro.
+//!
+//! ### TS0011
+//!
+//! Glob imports are not supported inside the macro:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     use std::fmt::*;
+//!     pub trait Printable = Debug;
+//! }
+//! ```
+//!
+//! Import the items by name: `use std::fmt::Debug;`.
+//!
+//! ### TS0012
+//!
+//! `self` in a `use` declaration must refer to a module, e.g. `use std::fmt::{self, Debug};`:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     use {self};
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! ### TS0013
+//!
+//! Only `#![require(...)]`, `#![trait_set(...)]` and lint attributes (e.g.
+//! `#![allow(trait_set::possible_typo)]`) are accepted as inner attributes of the invocation:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #![cfg(unix)]
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! Put other attributes on the aliases or on the surrounding module.
+//!
+//! ### TS0014
+//!
+//! Unknown option in `#[trait_set(...)]` attribute of an alias:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #[trait_set(require_docs)]
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! See the [`trait_set`] macro documentation for the options of aliases. Options of the whole
+//! invocation, such as `require_docs`, go to `#![trait_set(...)]` inner attribute.
+//!
+//! ### TS0015
+//!
+//! Unknown option or an invalid option value in `#![trait_set(...)]` attribute of the invocation:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #![trait_set(missing_docs = "deny")]
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! See the [`trait_set`] macro documentation for the options and their values.
+//!
+//! ### TS0016
+//!
+//! Lint attributes inside the invocation only control the lints of the macro,
+//! e.g. `#![allow(trait_set::possible_typo)]`:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #![allow(dead_code)]
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! Put compiler lints on the aliases or on the surrounding module.
+//!
+//! ### TS0017
+//!
+//! `pub` aliases must be documented when `require_docs` option is set:
+//!
+#![cfg_attr(feature = "extras", doc = "```compile_fail")]
+#![cfg_attr(not(feature = "extras"), doc = "```ignore")]
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #![trait_set(require_docs)]
+//!     pub trait ThreadSafe = Send + Sync;
+//! }
+//! ```
+//!
+//! Add a doc-comment to the alias.
+//!
+//! ### TS0018
+//!
+//! The alias uses a trait rejected by `forbid(...)` option:
+//!
+#![cfg_attr(feature = "extras", doc = "```compile_fail")]
+#![cfg_attr(not(feature = "extras"), doc = "```ignore")]
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #![trait_set(forbid(Copy))]
+//!     pub trait Value = Copy + Send;
+//! }
+//! ```
+//!
+//! Remove the trait from the bounds.
+//!
+//! ### TS0019
+//!
+//! One of `TRAIT_SET_*` environment variables has an invalid value, e.g.
+//! `TRAIT_SET_REQUIRE_DOCS=yes` instead of `true`. Fix the value in the build environment.
+//!
+//! ### TS0020
+//!
+//! The used functionality requires a cargo feature of `trait-set`, e.g. const trait aliases
+//! require `nightly` feature. Enable the feature mentioned in the error.
+//!
+//! ### TS0021
+//!
+//! The diagnostics file set via `TRAIT_SET_DIAGNOSTICS_FILE` can't be written, e.g. because
+//! its directory doesn't exist. Fix the path or the permissions.
+//!
+//! ### TS0022
+//!
+//! Inline aliases (`#[trait_set(inline)]`) and bound groups (`set Common = Send + Sync;`)
+//! are written out in place in other aliases, so they can't have generic parameters, a where
+//! clause or companion items such as `bounds_str`, and can't refer to each other in a loop.
+//! Bound groups can't have a visibility either:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #[trait_set(inline)]
+//!     trait Items<T> = Iterator<Item = T>;
+//! }
+//! ```
+//!
+//! Make the alias a regular one by removing `inline` option.
+//!
+//! ### TS0023
+//!
+//! Braces of an alias family have different numbers of alternatives:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait {Read, Write}Job = Send + std::io::{Read, Write, Seek};
+//! }
+//! ```
+//!
+//! List the same number of alternatives in all braces of the declaration.
+//!
+//! ### TS0024
+//!
+//! Alias bounds contain an unknown shorthand:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Service = Clone + @all;
+//! }
+//! ```
+//!
+//! The only supported shorthand is `@auto`.
+//!
+//! ### TS0025
+//!
+//! `#[trait_set::seal]` is applied to an item other than a trait:
+//!
+//! ```compile_fail
+//! #[trait_set::seal(u8)]
+//! pub struct Key;
+//! ```
+//!
+//! Apply the attribute to a trait declaration.
+//!
+//! ### TS0026
+//!
+//! A trait declared with `#[trait_set::alias]` has items or no bounds, or is `auto`:
+//!
+//! ```compile_fail
+//! #[trait_set::alias(Send + Sync)]
+//! pub trait ThreadSafe {
+//!     fn check(&self);
+//! }
+//! ```
+//!
+//! Remove the items: aliases have no items of their own.
+//!
+//! ### TS0027
+//!
+//! A sealed alias (`#[trait_set(sealed)]`) has generic parameters or bounds under
+//! `#[cfg(...)]`, or is inline or const:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #[trait_set(sealed)]
+//!     pub trait Items<T> = Iterator<Item = T>;
+//! }
+//! ```
+//!
+//! Declare a separate sealed alias for every parameter value, or remove `sealed` option.
+//!
+//! ### TS0028
+//!
+//! An alias with `hrtb_alias` option has no lifetime parameters, or its lifetime parameters
+//! have bounds or are used outside of the alias bounds (e.g. `T: 'a`), which higher-ranked
+//! trait bounds don't support:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     #[trait_set(hrtb_alias = "AnyItems")]
+//!     pub trait Items<T> = Iterator<Item = T>;
+//! }
+//! ```
+//!
+//! Remove the option or the bounds using the lifetime parameters.
+//!
+//! ### TS0029
+//!
+//! Bounds of an alias are written after `=`, not after `:` as supertraits:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait ThreadSafe: Send + Sync;
+//! }
+//! ```
+//!
+//! Use `=` instead: `pub trait ThreadSafe = Send + Sync;`.
+//!
+//! ### TS0030
+//!
+//! A bound of an alias has an attribute other than `#[cfg(...)]`, or the bounds and generic
+//! parameters of an alias have more than 6 distinct conditions, since every combination
+//! of them is a separate variant of the alias:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Storage = std::io::Read + #[cfg_attr(unix, doc = "Writer")] std::io::Write;
+//! }
+//! ```
+//!
+//! Remove the attribute, or declare the alias under `#[cfg(...)]` as a whole.
+//!
+//! ### TS0031
+//!
+//! `_` is used in bounds of generic parameters or in the where clause of an alias. Only `_`
+//! in the alias bounds is replaced with a new generic parameter:
+//!
+//! ```compile_fail
+//! # use trait_set::trait_set;
+//! trait_set! {
+//!     pub trait Convert<T: Into<_>> = From<T>;
+//! }
+//! ```
+//!
+//! Declare the parameter explicitly: `pub trait Convert<T: Into<U>, U> = From<T>;`.
+//!
 //! [alias]: https://doc.rust-lang.org/unstable-book/language-features/trait-alias.html
 //! [tracking_issue]: https://github.com/rust-lang/rust/issues/41517
 //! [`trait_set`]: macro.trait_set.html
+//! [`static_assertions`]: https://docs.rs/static_assertions
 
 extern crate proc_macro;
 
-use std::iter::FromIterator;
+#[cfg(feature = "extras")]
+use std::collections::HashSet;
+use std::{collections::HashMap, iter::FromIterator};
 
 use proc_macro::TokenStream;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Delimiter, Group, Punct, Spacing, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
-    parse::{Error, Parse, ParseStream},
-    parse_macro_input,
-    punctuated::Punctuated,
+    braced, bracketed,
+    parse::{Error, Nothing, Parse, ParseStream, Parser},
+    parse_macro_input, parse_quote, parse_quote_spanned,
+    punctuated::{Pair, Punctuated},
     spanned::Spanned,
-    Attribute, GenericParam, Generics, Ident, Lit, Meta, MetaNameValue, Result, Token,
-    TypeTraitObject, Visibility,
+    token, Attribute, DeriveInput, GenericParam, Generics, Ident, Lifetime, Lit, Meta,
+    MetaNameValue, NestedMeta, Path, PathSegment, Result, Token, TraitBound, TraitBoundModifier,
+    Type, TypeParamBound, TypeTraitObject, Visibility, WhereClause, WherePredicate,
+};
+#[cfg(feature = "extras")]
+use syn::{
+    visit::{self, Visit},
+    visit_mut::{self, VisitMut},
+    ItemUse, UseTree,
 };
 
-/// Represents one trait alias.
-struct TraitSet {
-    doc_comment: Option<String>,
-    visibility: Visibility,
-    _trait_token: Token![trait],
-    alias_name: Ident,
+/// Custom keywords of the macro input.
+mod kw {
+    syn::custom_keyword!(set);
+}
+
+/// Per-alias options set via `#[trait_set(...)]` attribute.
+#[derive(Clone, Default)]
+struct AliasOptions {
+    /// Whether the generated items should allow usage of deprecated traits.
+    allow_deprecated_bounds: bool,
+    /// Name of the string constant with the alias bounds, if it should be generated.
+    bounds_str: Option<Ident>,
+    /// Name of the macro expanding to the alias bounds, if it should be generated.
+    bounds_macro: Option<Ident>,
+    /// Visibility of the companion items (e.g. the `bounds_str` constant),
+    /// if it differs from the visibility of the alias.
+    helpers_vis: Option<Visibility>,
+    /// Whether the alias is only a building block for other aliases of the invocation,
+    /// so that it's flattened into them and no items are generated for it.
+    inline: bool,
+    /// Name of the `dyn Alias` type alias, if it should be generated.
+    dyn_alias: Option<Ident>,
+    /// Name of the `Box<dyn Alias>` type alias, if it should be generated.
+    box_alias: Option<Ident>,
+    /// Whether the expansion of the alias should be reported as a warning.
+    #[cfg_attr(not(feature = "extras"), allow(dead_code))]
+    debug: bool,
+    /// Whether a check that the alias can be used as a trait object should be generated.
+    assert_object_safe: bool,
+    /// Whether `assert_impl_Alias!` macro checking that types implement the alias
+    /// should be generated.
+    assertions: bool,
+    /// Whether the "Alias for" section listing the bounds is omitted from the alias docs.
+    no_bounds_doc: bool,
+    /// Name of the alias of the alias for any lifetimes, e.g. `for<'a> Alias<'a>`,
+    /// if it should be generated.
+    hrtb_alias: Option<Ident>,
+    /// Name of the type parameter of the blanket impl standing for the implementor,
+    /// if it's set explicitly.
+    impl_param: Option<Ident>,
+    /// Whether the alias is sealed: instead of the blanket impl, it's implemented
+    /// for the chosen types via `impl_Alias!` macro.
+    sealed: bool,
+    /// Name of the macro implementing the sealed alias, if it's set explicitly.
+    sealed_macro: Option<Ident>,
+}
+
+impl AliasOptions {
+    /// Collects options from all the `#[trait_set(...)]` attributes of the alias.
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = Self::default();
+
+        for attr in attrs {
+            if !attr.path.is_ident("trait_set") {
+                continue;
+            }
+            let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            for meta in nested {
+                match meta {
+                    Meta::Path(path) if path.is_ident("allow_deprecated_bounds") => {
+                        options.allow_deprecated_bounds = true;
+                    }
+                    Meta::Path(path) if path.is_ident("assert_object_safe") => {
+                        options.assert_object_safe = true;
+                    }
+                    Meta::Path(path) if path.is_ident("assertions") => {
+                        options.assertions = true;
+                    }
+                    #[cfg(feature = "extras")]
+                    Meta::Path(path) if path.is_ident("debug") => {
+                        options.debug = true;
+                    }
+                    #[cfg(not(feature = "extras"))]
+                    Meta::Path(path) if path.is_ident("debug") => {
+                        return Err(Error::new(
+                            path.span(),
+                            "TS0020: `debug` option requires `extras` feature of `trait-set`",
+                        ));
+                    }
+                    Meta::Path(path) if path.is_ident("inline") => {
+                        options.inline = true;
+                    }
+                    Meta::Path(path) if path.is_ident("no_bounds_doc") => {
+                        options.no_bounds_doc = true;
+                    }
+                    Meta::Path(path) if path.is_ident("sealed") => {
+                        options.sealed = true;
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("bounds_str") => {
+                        options.bounds_str = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("bounds_macro") => {
+                        options.bounds_macro = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("sealed") => {
+                        options.sealed = true;
+                        options.sealed_macro = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("hrtb_alias") => {
+                        options.hrtb_alias = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("impl_param") => {
+                        options.impl_param = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("helpers_vis") => {
+                        options.helpers_vis = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("dyn_alias") => {
+                        options.dyn_alias = Some(value.parse()?);
+                    }
+                    Meta::NameValue(MetaNameValue {
+                        path,
+                        lit: Lit::Str(value),
+                        ..
+                    }) if path.is_ident("box_alias") => {
+                        options.box_alias = Some(value.parse()?);
+                    }
+                    other => {
+                        return Err(Error::new(
+                            other.span(),
+                            "TS0014: Unknown option, expected `allow_deprecated_bounds`, `assert_object_safe`, `assertions`, \
+                             `bounds_macro`, `bounds_str`, `debug`, \
+                             `helpers_vis`, `hrtb_alias`, `impl_param`, `inline`, `no_bounds_doc`, `sealed`, `dyn_alias` or `box_alias`",
+                        ));
+                    }
+                }
+            }
+        }
+
+        #[cfg(not(feature = "extras"))]
+        {
+            if let Some(name) = &options.bounds_str {
+                return Err(Error::new(
+                    name.span(),
+                    "TS0020: `bounds_str` option requires `extras` feature of `trait-set`",
+                ));
+            }
+        }
+
+        Ok(options)
+    }
+
+    /// Renders attributes required by the options, that must be applied to both
+    /// the trait and the blanket impl.
+    fn render_item_attrs(&self) -> TokenStream2 {
+        if self.allow_deprecated_bounds {
+            quote! { #[allow(deprecated)] }
+        } else {
+            TokenStream2::new()
+        }
+    }
+}
+
+/// Auto traits `@auto` shorthand expands to, with the paths used in the bounds.
+const AUTO_TRAITS: &[(&str, &str)] = &[
+    ("Send", "::core::marker::Send"),
+    ("Sync", "::core::marker::Sync"),
+    ("Unpin", "::core::marker::Unpin"),
+    ("UnwindSafe", "::core::panic::UnwindSafe"),
+    ("RefUnwindSafe", "::core::panic::RefUnwindSafe"),
+];
+
+/// Way to handle aliases without doc-comments, so that crates
+/// with `#![deny(missing_docs)]` can use them.
+#[derive(Clone, Copy)]
+enum MissingDocs {
+    /// Add `#[allow(missing_docs)]` to the trait.
+    Allow,
+    /// Generate a doc-comment from the alias bounds.
+    Generate,
+}
+
+/// Level of a lint emitted by the macro, set via inner lint attributes,
+/// e.g. `#![allow(trait_set::possible_typo)]`.
+#[derive(Clone, Copy, PartialEq)]
+enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    /// Names of the lints emitted by the macro.
+    const LINTS: &'static [&'static str] = &[
+        "possible_typo",
+        "private_component",
+        "redundant_bounds",
+        "shadowed_bound",
+        "too_many_bounds",
+    ];
+
+    /// Lints that are only emitted if enabled explicitly, e.g.
+    /// `#![warn(trait_set::redundant_bounds)]`, since they can fire on valid input.
+    /// Enabling them by default would break crates built with `#![deny(warnings)]`.
+    #[cfg(feature = "extras")]
+    const ALLOWED_BY_DEFAULT: &'static [&'static str] = &[
+        "possible_typo",
+        "private_component",
+        "redundant_bounds",
+        "shadowed_bound",
+    ];
+
+    /// Returns the level set by the attribute, if it's a lint attribute.
+    fn from_attr(attr: &Attribute) -> Option<Self> {
+        if attr.path.is_ident("allow") {
+            Some(LintLevel::Allow)
+        } else if attr.path.is_ident("warn") {
+            Some(LintLevel::Warn)
+        } else if attr.path.is_ident("deny") {
+            Some(LintLevel::Deny)
+        } else {
+            None
+        }
+    }
+}
+
+/// Invocation-wide options set via `#![trait_set(...)]` attribute.
+/// Without `extras` feature the options that only affect checks are parsed,
+/// but have no effect.
+#[derive(Default)]
+#[cfg_attr(not(feature = "extras"), allow(dead_code))]
+struct InvocationOptions {
+    /// Handling of aliases without doc-comments, if set.
+    missing_docs: Option<MissingDocs>,
+    /// Whether `pub` aliases must have doc-comments.
+    require_docs: bool,
+    /// Traits that must not be used in alias bounds.
+    forbidden: Vec<Path>,
+    /// Maximum number of bounds of an alias, if set.
+    max_bounds: Option<usize>,
+    /// Template of the doc-comment generated for aliases, if overridden.
+    generated_doc: Option<String>,
+    /// Template of the doc-comment of `bounds_str` constants, if overridden.
+    bounds_str_doc: Option<String>,
+    /// Whether docs of aliases should show the equivalent nightly trait alias.
+    nightly_equivalent: bool,
+    /// Whether aliases should get `#[diagnostic::on_unimplemented]` listing their bounds.
+    #[cfg_attr(not(feature = "extras"), allow(dead_code))]
+    on_unimplemented: bool,
+    /// Levels of the macro lints overridden via inner lint attributes.
+    lint_levels: HashMap<String, LintLevel>,
+    /// Paths of the traits `@auto` shorthand expands to, if overridden.
+    auto_traits: Option<Vec<&'static str>>,
+}
+
+impl InvocationOptions {
+    /// Creates options with defaults overridden by environment variables, so that
+    /// CI can tighten the checks without code changes:
+    ///
+    /// - `TRAIT_SET_MISSING_DOCS`: default for `missing_docs` option.
+    /// - `TRAIT_SET_REQUIRE_DOCS`: enables `require_docs` option if set to `1` or `true`.
+    /// - `TRAIT_SET_FORBID`: comma-separated list of traits added to `forbid` option.
+    /// - `TRAIT_SET_MAX_BOUNDS`: default for `max_bounds` option.
+    fn from_env() -> Result<Self> {
+        let mut options = Self::default();
+
+        if let Some(value) = Self::env_var("TRAIT_SET_MISSING_DOCS")? {
+            options.missing_docs = Some(match value.as_str() {
+                "allow" => MissingDocs::Allow,
+                "generate" => MissingDocs::Generate,
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "TS0019: Invalid `TRAIT_SET_MISSING_DOCS` environment variable, expected `allow` or `generate`",
+                    ));
+                }
+            });
+        }
+        if let Some(value) = Self::env_var("TRAIT_SET_REQUIRE_DOCS")? {
+            options.require_docs = match value.as_str() {
+                "1" | "true" => true,
+                "0" | "false" => false,
+                _ => {
+                    return Err(Error::new(
+                        Span::call_site(),
+                        "TS0019: Invalid `TRAIT_SET_REQUIRE_DOCS` environment variable, expected `true` or `false`",
+                    ));
+                }
+            };
+        }
+        if let Some(value) = Self::env_var("TRAIT_SET_FORBID")? {
+            for path in value
+                .split(',')
+                .map(str::trim)
+                .filter(|path| !path.is_empty())
+            {
+                let path = syn::parse_str(path).map_err(|_| {
+                    Error::new(
+                        Span::call_site(),
+                        format!(
+                            "TS0019: Invalid `TRAIT_SET_FORBID` environment variable, `{}` is not a path",
+                            path
+                        ),
+                    )
+                })?;
+                options.forbidden.push(path);
+            }
+        }
+        if let Some(value) = Self::env_var("TRAIT_SET_MAX_BOUNDS")? {
+            options.max_bounds = Some(value.parse().map_err(|_| {
+                Error::new(
+                    Span::call_site(),
+                    "TS0019: Invalid `TRAIT_SET_MAX_BOUNDS` environment variable, expected a number",
+                )
+            })?);
+        }
+
+        Ok(options)
+    }
+
+    /// Reads the environment variable, treating an empty value as unset.
+    fn env_var(name: &str) -> Result<Option<String>> {
+        match std::env::var(name) {
+            Ok(value) if value.is_empty() => Ok(None),
+            Ok(value) => Ok(Some(value)),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(std::env::VarError::NotUnicode(_)) => Err(Error::new(
+                Span::call_site(),
+                format!(
+                    "TS0019: `{}` environment variable is not valid unicode",
+                    name
+                ),
+            )),
+        }
+    }
+
+    /// Returns the bounds `@auto` shorthand expands to, spanned at the shorthand.
+    fn auto_traits(&self, span: Span) -> Vec<TypeParamBound> {
+        let paths = match &self.auto_traits {
+            Some(paths) => paths.clone(),
+            None => AUTO_TRAITS.iter().map(|(_, path)| *path).collect(),
+        };
+        paths
+            .into_iter()
+            .map(|path| {
+                let tokens: TokenStream2 = path
+                    .parse::<TokenStream2>()
+                    .expect("Auto trait paths are valid")
+                    .into_iter()
+                    .map(|mut token| {
+                        token.set_span(span);
+                        token
+                    })
+                    .collect();
+                syn::parse2(tokens).expect("Auto trait paths are valid")
+            })
+            .collect()
+    }
+
+    /// Applies options from a single `#![trait_set(...)]` attribute.
+    fn parse_attr(&mut self, attr: &Attribute) -> Result<()> {
+        let nested = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in nested {
+            match meta {
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(value),
+                    ..
+                }) if path.is_ident("missing_docs") => {
+                    self.missing_docs = match value.value().as_str() {
+                        "allow" => Some(MissingDocs::Allow),
+                        "generate" => Some(MissingDocs::Generate),
+                        _ => {
+                            return Err(Error::new(
+                                value.span(),
+                                "TS0015: Unknown `missing_docs` value, expected `\"allow\"` or `\"generate\"`",
+                            ));
+                        }
+                    };
+                }
+                Meta::Path(path) if path.is_ident("require_docs") => {
+                    self.require_docs = true;
+                }
+                Meta::Path(path) if path.is_ident("nightly_equivalent") => {
+                    self.nightly_equivalent = true;
+                }
+                #[cfg(feature = "extras")]
+                Meta::Path(path) if path.is_ident("on_unimplemented") => {
+                    self.on_unimplemented = true;
+                }
+                #[cfg(not(feature = "extras"))]
+                Meta::Path(path) if path.is_ident("on_unimplemented") => {
+                    return Err(Error::new(
+                        path.span(),
+                        "TS0020: `on_unimplemented` option requires `extras` feature of `trait-set`",
+                    ));
+                }
+                Meta::List(list) if list.path.is_ident("auto_traits") => {
+                    let mut auto_traits = Vec::new();
+                    for nested in list.nested {
+                        let path = match &nested {
+                            NestedMeta::Meta(Meta::Path(path)) => AUTO_TRAITS
+                                .iter()
+                                .find(|(name, _)| path.is_ident(name))
+                                .map(|(_, path)| *path),
+                            _ => None,
+                        };
+                        match path {
+                            Some(path) => auto_traits.push(path),
+                            None => {
+                                return Err(Error::new(
+                                    nested.span(),
+                                    "TS0015: Expected one of `Send`, `Sync`, `Unpin`, `UnwindSafe` \
+                                     or `RefUnwindSafe`",
+                                ));
+                            }
+                        }
+                    }
+                    self.auto_traits = Some(auto_traits);
+                }
+                Meta::List(list) if list.path.is_ident("forbid") => {
+                    for nested in list.nested {
+                        match nested {
+                            NestedMeta::Meta(Meta::Path(path)) => self.forbidden.push(path),
+                            other => {
+                                return Err(Error::new(
+                                    other.span(),
+                                    "TS0015: Expected a trait path",
+                                ));
+                            }
+                        }
+                    }
+                }
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(value),
+                    ..
+                }) if path.is_ident("max_bounds") => {
+                    self.max_bounds = Some(value.base10_parse()?);
+                }
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(value),
+                    ..
+                }) if path.is_ident("generated_doc") => {
+                    self.generated_doc = Some(value.value());
+                }
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(value),
+                    ..
+                }) if path.is_ident("bounds_str_doc") => {
+                    self.bounds_str_doc = Some(value.value());
+                }
+                other => {
+                    return Err(Error::new(
+                        other.span(),
+                        "TS0015: Unknown option, expected `missing_docs`, `require_docs`, `forbid`, \
+                         `max_bounds`, `generated_doc`, `bounds_str_doc`, `nightly_equivalent`, \
+                         `auto_traits` or `on_unimplemented`",
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Applies lint levels from a single inner lint attribute,
+    /// e.g. `#![allow(trait_set::possible_typo)]`.
+    fn parse_lint_attr(&mut self, attr: &Attribute, level: LintLevel) -> Result<()> {
+        let lints = attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+        for lint in lints {
+            let name = match lint.segments.len() {
+                2 if lint.segments[0].ident == "trait_set" => lint.segments[1].ident.to_string(),
+                _ => {
+                    return Err(Error::new(
+                        lint.span(),
+                        "TS0016: Only lints of the macro can be controlled inside `trait_set!`, \
+                         e.g. `trait_set::possible_typo`",
+                    ));
+                }
+            };
+            if !LintLevel::LINTS.contains(&name.as_str()) {
+                return Err(Error::new(
+                    lint.segments[1].ident.span(),
+                    format!(
+                        "TS0016: Unknown lint `trait_set::{}`, expected one of: {}",
+                        name,
+                        LintLevel::LINTS.join(", ")
+                    ),
+                ));
+            }
+            self.lint_levels.insert(name, level);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the level of the lint, either set in the invocation or the default one.
+    #[cfg(feature = "extras")]
+    fn lint_level(&self, name: &str) -> LintLevel {
+        match self.lint_levels.get(name) {
+            Some(level) => *level,
+            None if LintLevel::ALLOWED_BY_DEFAULT.contains(&name) => LintLevel::Allow,
+            None => LintLevel::Warn,
+        }
+    }
+
+    /// Checks that the alias is documented if it's required.
+    #[cfg(feature = "extras")]
+    fn check_docs(&self, alias: &TraitSet) -> Result<()> {
+        if self.require_docs
+            && matches!(alias.visibility, Visibility::Public(_))
+            && !alias.is_documented()
+        {
+            return Err(Error::new(
+                alias.alias_name.span(),
+                format!(
+                    "TS0017: Public alias `{}` must be documented, as required by `#![trait_set(require_docs)]`",
+                    alias.alias_name
+                ),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the alias bounds don't contain forbidden traits, including the bounds
+    /// in the where clause, on generic parameters and on associated types.
+    ///
+    /// Paths are compared by their trailing segments, so that `forbid(Copy)`
+    /// also matches `std::marker::Copy`.
+    #[cfg(feature = "extras")]
+    fn check_forbidden(&self, alias: &TraitSet) -> Result<()> {
+        let mut finder = ForbiddenFinder {
+            forbidden: &self.forbidden,
+            found: None,
+        };
+        finder.visit_type_trait_object(&alias.traits);
+        finder.visit_generics(&alias.generics);
+        if let Some((forbidden, ident)) = finder.found {
+            return Err(Error::new(
+                ident.span(),
+                format!(
+                    "TS0018: `{}` is forbidden in aliases of this invocation by `#![trait_set(forbid(...))]` policy",
+                    render_tokens(quote! { #forbidden }),
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Finds the first trait bound that refers to one of the forbidden traits.
+#[cfg(feature = "extras")]
+struct ForbiddenFinder<'a> {
+    forbidden: &'a [Path],
+    /// The matched forbidden path and the last segment of the bound.
+    found: Option<(&'a Path, &'a Ident)>,
+}
+
+#[cfg(feature = "extras")]
+impl<'a> Visit<'a> for ForbiddenFinder<'a> {
+    fn visit_trait_bound(&mut self, bound: &'a TraitBound) {
+        if self.found.is_none() {
+            let segments = &bound.path.segments;
+            let forbidden = self.forbidden.iter().find(|forbidden| {
+                forbidden.segments.len() <= segments.len()
+                    && forbidden
+                        .segments
+                        .iter()
+                        .rev()
+                        .zip(segments.iter().rev())
+                        .all(|(forbidden, segment)| forbidden.ident == segment.ident)
+            });
+            if let Some(forbidden) = forbidden {
+                let last_segment = segments.last().expect("Paths are never empty");
+                self.found = Some((forbidden, &last_segment.ident));
+            }
+        }
+        visit::visit_trait_bound(self, bound);
+    }
+}
+
+/// Returns the paths of the bounds if all of them are plain trait paths, e.g. `Send` or
+/// `Iterator<Item = u8>`, which is what `static_assertions` macros accept.
+#[cfg(feature = "static-assertions")]
+fn trait_paths<'a>(bounds: impl IntoIterator<Item = &'a TypeParamBound>) -> Option<Vec<&'a Path>> {
+    bounds
+        .into_iter()
+        .map(|bound| match bound {
+            TypeParamBound::Trait(TraitBound {
+                paren_token: None,
+                modifier: TraitBoundModifier::None,
+                lifetimes: None,
+                path,
+            }) => Some(path),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Replaces `Self` in the tokens with `implementor`, the type parameter of the generated items.
+fn replace_self(tokens: TokenStream2, implementor: &Ident) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) if ident == "Self" => {
+                TokenTree::Ident(Ident::new(&implementor.to_string(), ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut replaced =
+                    Group::new(group.delimiter(), replace_self(group.stream(), implementor));
+                replaced.set_span(group.span());
+                replaced.into()
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Replaces `crate` at the start of paths in the tokens with `$crate`, so that
+/// the paths can be used in a macro exported to other crates.
+fn replace_crate(tokens: TokenStream2) -> TokenStream2 {
+    let mut replaced = TokenStream2::new();
+    let mut prev_is_colon = false;
+    for token in tokens {
+        let is_colon = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ':');
+        match token {
+            TokenTree::Ident(ident) if ident == "crate" && !prev_is_colon => {
+                let mut dollar = Punct::new('$', Spacing::Alone);
+                dollar.set_span(ident.span());
+                replaced.extend(vec![TokenTree::Punct(dollar), TokenTree::Ident(ident)]);
+            }
+            TokenTree::Group(group) => {
+                let mut new_group = Group::new(group.delimiter(), replace_crate(group.stream()));
+                new_group.set_span(group.span());
+                replaced.extend(Some(TokenTree::Group(new_group)));
+            }
+            token => replaced.extend(Some(token)),
+        }
+        prev_is_colon = is_colon;
+    }
+    replaced
+}
+
+/// Returns whether the tokens refer to `Self`.
+fn mentions_self(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => ident == "Self",
+        TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
+
+/// Returns the span of the first mention of one of `lifetimes` in the tokens.
+fn find_lifetime(tokens: TokenStream2, lifetimes: &[&Lifetime]) -> Option<Span> {
+    let mut prev_quote: Option<Span> = None;
+    for token in tokens {
+        match &token {
+            TokenTree::Ident(ident)
+                if lifetimes.iter().any(|lifetime| lifetime.ident == *ident) =>
+            {
+                if let Some(quote_span) = prev_quote {
+                    return Some(quote_span.join(ident.span()).unwrap_or(ident.span()));
+                }
+            }
+            TokenTree::Group(group) => {
+                if let Some(span) = find_lifetime(group.stream(), lifetimes) {
+                    return Some(span);
+                }
+            }
+            _ => {}
+        }
+        prev_quote = match &token {
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => Some(punct.span()),
+            _ => None,
+        };
+    }
+    None
+}
+
+/// Returns the span of `const` in `~const Trait` bound, which syn represents as
+/// a path starting with `const` segment.
+fn const_bound_span(bound: &TypeParamBound) -> Option<Span> {
+    match bound {
+        TypeParamBound::Trait(bound) if bound.path.segments.len() > 1 => bound
+            .path
+            .segments
+            .first()
+            .filter(|segment| segment.ident == "const")
+            .map(|segment| segment.ident.span()),
+        _ => None,
+    }
+}
+
+/// Renders the private module with the `Sealed` supertrait of the sealed trait `name`,
+/// returning the module name along with it.
+/// The module is private, so its `Sealed` trait can't be named by other modules.
+fn render_sealed_module(name: &Ident) -> (Ident, TokenStream2) {
+    let module = Ident::new(
+        &format!(
+            "__trait_set_sealed_{}",
+            name.to_string().trim_start_matches("r#")
+        ),
+        name.span(),
+    );
+    let tokens = quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #module {
+            pub trait Sealed {}
+        }
+    };
+    (module, tokens)
+}
+
+/// Renders the bounds list into a human-readable string.
+#[cfg(feature = "extras")]
+fn render_bounds(bounds: &Punctuated<TypeParamBound, Token![+]>) -> String {
+    render_tokens(quote! { #bounds })
+}
+
+/// Renders tokens into a string formatted the way Rust code is usually written.
+///
+/// `TokenStream::to_string` puts spaces between every pair of tokens
+/// (e.g. `Iterator < Item = T >`), which doesn't look good in documentation.
+#[cfg(feature = "extras")]
+fn render_tokens(tokens: TokenStream2) -> String {
+    let mut out = String::new();
+    // Whether the next token must be written without a space before it.
+    let mut glue_next = true;
+    let mut prev_is_ident = false;
+    let mut prev_joint = None;
+
+    for token in tokens {
+        // For every token we decide whether it sticks to the previous token
+        // and to the next one.
+        let (text, glue_before, glue_after) = match &token {
+            TokenTree::Punct(punct) => {
+                let (glue_before, glue_after) = match punct.as_char() {
+                    ',' | ';' => (true, false),
+                    // Either a part of a path separator or a standalone colon.
+                    ':' if punct.spacing() == Spacing::Joint || prev_joint == Some(':') => {
+                        (true, true)
+                    }
+                    ':' => (true, false),
+                    '<' => (true, true),
+                    '>' => (true, false),
+                    '&' | '\'' | '?' | '!' | '#' | '~' => (false, true),
+                    _ => (false, false),
+                };
+                // Multi-character punctuation, e.g. `::` or `->`.
+                let glue_after = glue_after || punct.spacing() == Spacing::Joint;
+                (punct.as_char().to_string(), glue_before, glue_after)
+            }
+            TokenTree::Group(group) => {
+                let inner = render_tokens(group.stream());
+                let (text, glue_before) = match group.delimiter() {
+                    Delimiter::Parenthesis => (format!("({})", inner), prev_is_ident),
+                    Delimiter::Bracket => (format!("[{}]", inner), false),
+                    Delimiter::Brace if inner.is_empty() => (String::from("{}"), false),
+                    Delimiter::Brace => (format!("{{ {} }}", inner), false),
+                    Delimiter::None => (inner, false),
+                };
+                (text, glue_before, false)
+            }
+            // Paths passed through exported macros start with `$crate`.
+            TokenTree::Ident(ident) if ident == "$crate" => (String::from("crate"), false, false),
+            TokenTree::Ident(ident) => (ident.to_string(), false, false),
+            TokenTree::Literal(literal) => (literal.to_string(), false, false),
+        };
+
+        if !glue_next && !glue_before {
+            out.push(' ');
+        }
+        out += &text;
+        glue_next = glue_after;
+        prev_is_ident = matches!(token, TokenTree::Ident(_));
+        prev_joint = match &token {
+            TokenTree::Punct(punct) if punct.spacing() == Spacing::Joint => Some(punct.as_char()),
+            _ => None,
+        };
+    }
+
+    out
+}
+
+/// Renders items into a string with an item per line, e.g. to show the expansion
+/// of an alias.
+#[cfg(feature = "extras")]
+fn render_items(tokens: TokenStream2) -> String {
+    let mut items = Vec::new();
+    let mut item = TokenStream2::new();
+    for token in tokens {
+        // Items end with a semicolon or with a body in braces, except for the
+        // braces of `const _: () = { ... };`.
+        let is_end = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == ';',
+            TokenTree::Group(group) => {
+                group.delimiter() == Delimiter::Brace
+                    && !matches!(item.clone().into_iter().last(), Some(TokenTree::Punct(punct)) if punct.as_char() == '=')
+            }
+            _ => false,
+        };
+        item.extend(Some(token));
+        if is_end {
+            items.push(render_tokens(std::mem::take(&mut item)));
+        }
+    }
+    if !item.is_empty() {
+        items.push(render_tokens(item));
+    }
+    items.join("\n")
+}
+
+/// Warning emitted by the macro.
+///
+/// Proc-macros can't emit warnings on stable Rust, so the warning is rendered
+/// as a use of a deprecated constant, with deprecation note being the message.
+#[cfg(feature = "extras")]
+struct Warning {
+    /// Name of the warning, also used as a name of the deprecated constant.
+    name: &'static str,
+    message: String,
+    /// Replacement for the code at the span, if there is an obvious one.
+    #[cfg_attr(not(feature = "diagnostics-file"), allow(dead_code))]
+    suggestion: Option<String>,
+    span: Span,
+}
+
+#[cfg(feature = "extras")]
+impl Warning {
+    fn render(&self) -> TokenStream2 {
+        let name = Ident::new(self.name, Span::call_site());
+        let usage = Ident::new(self.name, self.span);
+        let message = &self.message;
+        quote! {
+            const _: () = {
+                #[deprecated(note = #message)]
+                #[allow(non_upper_case_globals)]
+                const #name: () = ();
+                #usage
+            };
+        }
+    }
+}
+
+/// Diagnostic of the macro written to the file set via `TRAIT_SET_DIAGNOSTICS_FILE`
+/// environment variable, so that external tools can collect them without parsing
+/// the compiler output.
+#[cfg(feature = "diagnostics-file")]
+struct Diagnostic {
+    /// `error` or `warning`.
+    level: &'static str,
+    /// Name of the lint for warnings, code for errors (e.g. `TS0003`),
+    /// or `error` for the parse errors that have no code.
+    code: String,
+    message: String,
+    suggestion: Option<String>,
+    span: Span,
+}
+
+#[cfg(feature = "diagnostics-file")]
+impl Diagnostic {
+    /// Creates diagnostics for every message of the error.
+    fn from_error(error: &Error) -> Vec<Self> {
+        error
+            .clone()
+            .into_iter()
+            .map(|error| {
+                let message = error.to_string();
+                Diagnostic {
+                    level: "error",
+                    code: Self::error_code(&message).unwrap_or("error").to_string(),
+                    message,
+                    suggestion: None,
+                    span: error.span(),
+                }
+            })
+            .collect()
+    }
+
+    /// Extracts the code from the error message, e.g. `TS0003` from `TS0003: Where clause...`.
+    fn error_code(message: &str) -> Option<&str> {
+        let code = message.split(": ").next()?;
+        let is_code = code.len() == 6
+            && code.starts_with("TS")
+            && code[2..].chars().all(|c| c.is_ascii_digit());
+        if is_code {
+            Some(code)
+        } else {
+            None
+        }
+    }
+
+    /// Renders the diagnostic as a single-line JSON object.
+    fn to_json(&self) -> String {
+        let start = self.span.start();
+        format!(
+            "{{\"level\":{},\"code\":{},\"message\":{},\"suggestion\":{},\"file\":{},\"line\":{},\"column\":{}}}",
+            json_string(self.level),
+            json_string(&self.code),
+            json_string(&self.message),
+            self.suggestion
+                .as_deref()
+                .map_or_else(|| "null".to_string(), json_string),
+            json_string(&self.span.file()),
+            start.line,
+            // Columns are 1-based in the compiler output.
+            start.column + 1,
+        )
+    }
+
+    /// Writes the error to the diagnostics file, if any, and returns it
+    /// combined with the error of writing, if it happens.
+    fn write_error(mut error: Error) -> Error {
+        if let Err(write_error) = Self::write_all(&Self::from_error(&error)) {
+            error.combine(write_error);
+        }
+        error
+    }
+
+    /// Appends the diagnostics to the file set via `TRAIT_SET_DIAGNOSTICS_FILE`, if any.
+    fn write_all(diagnostics: &[Diagnostic]) -> Result<()> {
+        use std::io::Write;
+
+        let path = match InvocationOptions::env_var("TRAIT_SET_DIAGNOSTICS_FILE")? {
+            Some(path) if !diagnostics.is_empty() => path,
+            _ => return Ok(()),
+        };
+        // Every line is a complete JSON object, and the whole batch is written
+        // at once, so concurrent compiler processes don't mix their output.
+        let contents: String = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_json() + "\n")
+            .collect();
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .and_then(|mut file| file.write_all(contents.as_bytes()))
+            .map_err(|err| {
+                Error::new(
+                    Span::call_site(),
+                    format!("TS0021: Unable to write diagnostics to `{}`: {}", path, err),
+                )
+            })
+    }
+}
+
+/// Escapes the string as a JSON string literal.
+#[cfg(feature = "diagnostics-file")]
+fn json_string(value: &str) -> String {
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Names of the traits from `std` and `core` that are commonly used in bounds without
+/// a path, so that they are not reported as misspelled names of aliases.
+#[cfg(feature = "extras")]
+const WELL_KNOWN_TRAITS: &[&str] = &[
+    "Add",
+    "AsMut",
+    "AsRef",
+    "Borrow",
+    "BorrowMut",
+    "BufRead",
+    "Clone",
+    "Copy",
+    "Debug",
+    "Default",
+    "Deref",
+    "DerefMut",
+    "Display",
+    "Div",
+    "DoubleEndedIterator",
+    "Drop",
+    "Eq",
+    "Error",
+    "ExactSizeIterator",
+    "Extend",
+    "Fn",
+    "FnMut",
+    "FnOnce",
+    "From",
+    "FromIterator",
+    "FromStr",
+    "Future",
+    "Hash",
+    "Into",
+    "IntoIterator",
+    "Iterator",
+    "Mul",
+    "Neg",
+    "Not",
+    "Ord",
+    "PartialEq",
+    "PartialOrd",
+    "Read",
+    "RefUnwindSafe",
+    "Rem",
+    "Seek",
+    "Send",
+    "Sized",
+    "Sub",
+    "Sync",
+    "ToOwned",
+    "ToString",
+    "TryFrom",
+    "TryInto",
+    "Unpin",
+    "UnwindSafe",
+    "Write",
+];
+
+/// Returns the name of the bound if it's a trait referred to by a single identifier,
+/// e.g. a name of another alias.
+#[cfg(feature = "extras")]
+fn bound_ident(bound: &TypeParamBound) -> Option<&Ident> {
+    match bound {
+        TypeParamBound::Trait(bound) => bound.path.get_ident(),
+        TypeParamBound::Lifetime(_) => None,
+    }
+}
+
+/// Returns the key to compare bounds by: the name of the trait if it's a plain path
+/// without generic arguments, e.g. `Debug` for `std::fmt::Debug`, or the rendered
+/// bound otherwise.
+#[cfg(feature = "extras")]
+fn bound_key(bound: &TypeParamBound) -> String {
+    if let TypeParamBound::Trait(TraitBound {
+        lifetimes: None,
+        modifier: TraitBoundModifier::None,
+        path,
+        ..
+    }) = bound
+    {
+        if path
+            .segments
+            .iter()
+            .all(|segment| segment.arguments.is_empty())
+        {
+            if let Some(segment) = path.segments.last() {
+                return segment.ident.to_string();
+            }
+        }
+    }
+    render_tokens(quote! { #bound })
+}
+
+/// Calculates the Levenshtein distance between two strings.
+#[cfg(feature = "extras")]
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = prev_row[j] + if a_char == *b_char { 0 } else { 1 };
+            row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+        }
+        prev_row = row;
+    }
+
+    prev_row[b.len()]
+}
+
+/// Represents one trait alias.
+#[derive(Clone)]
+struct TraitSet {
+    /// `#[doc ...]` attributes of the alias, including doc-comments, re-emitted as is.
+    doc_attrs: Vec<Attribute>,
+    /// Doc-comments of generic parameters, taken out of the generics.
+    #[cfg_attr(not(feature = "extras"), allow(dead_code))]
+    param_docs: Vec<(String, String)>,
+    options: AliasOptions,
+    /// Lint attributes (e.g. `#[allow(...)]`) applied to the generated items.
+    lint_attrs: Vec<Attribute>,
+    /// `#[deprecated]` attribute applied to the trait and its companion items.
+    deprecated: Option<Attribute>,
+    /// `#[cfg(...)]` attributes applied to all the generated items.
+    cfg_attrs: Vec<Attribute>,
+    /// Other attributes not handled by the macro, e.g. `#[cfg_attr(...)]`,
+    /// applied to the trait only.
+    forwarded_attrs: Vec<Attribute>,
+    visibility: Visibility,
+    /// `const` keyword of const trait aliases, available on nightly only.
+    constness: Option<Token![const]>,
+    /// `unsafe` keyword of aliases of unsafe traits, making the blanket impl `unsafe` as well.
+    unsafety: Option<Token![unsafe]>,
+    _trait_token: Token![trait],
+    alias_name: Ident,
+    generics: Generics,
+    _eq_token: Token![=],
+    traits: TypeTraitObject,
+    /// Span of `@auto` shorthand in the bounds, which is replaced with the auto traits
+    /// once the invocation options are known.
+    auto: Option<Span>,
+    /// Bounds under `#[cfg(...)]` attributes, which are turned into variants of the alias
+    /// for every combination of the conditions.
+    cfg_bounds: Vec<(Vec<Attribute>, TypeParamBound)>,
+}
+
+impl TraitSet {
+    /// Attempts to parse doc-comments from the trait attributes
+    /// and returns the results as a single string.
+    /// If multiple doc-comments were provided (e.g. with `///` and `#[doc]`),
+    /// they will be joined with a newline.
+    fn parse_doc(attrs: &[Attribute]) -> Result<Option<String>> {
+        let mut out = String::new();
+
+        for attr in attrs {
+            // Check whether current attribute is `#[doc = "..."]`.
+            if let Meta::NameValue(MetaNameValue { path, lit, .. }) = attr.parse_meta()? {
+                if let Some(path_ident) = path.get_ident() {
+                    if path_ident == "doc" {
+                        if let Lit::Str(doc_comment) = lit {
+                            out += &doc_comment.value();
+                            // Newlines are not included in the literal value,
+                            // so we have to add them manually.
+                            out.push('\n');
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(if !out.is_empty() { Some(out) } else { None })
+    }
+
+    /// Returns whether the alias has a doc-comment, i.e. `#[doc = ...]` attribute, as opposed
+    /// to attributes like `#[doc(hidden)]`.
+    fn is_documented(&self) -> bool {
+        self.doc_attrs.iter().any(|attr| {
+            matches!(
+                attr.tokens.clone().into_iter().next(),
+                Some(TokenTree::Punct(punct)) if punct.as_char() == '='
+            )
+        })
+    }
+
+    /// Collects lint attributes, so that lints firing on the generated items
+    /// (e.g. `non_camel_case_types`) can be controlled at the alias.
+    fn parse_lint_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+        attrs
+            .iter()
+            .filter(|attr| {
+                ["allow", "warn", "deny", "forbid", "expect"]
+                    .iter()
+                    .any(|lint_level| attr.path.is_ident(lint_level))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the attributes that are not handled by the macro, to be forwarded
+    /// to the trait as is.
+    fn parse_forwarded_attrs(attrs: &[Attribute]) -> Vec<Attribute> {
+        const HANDLED: &[&str] = &[
+            "doc",
+            "trait_set",
+            "deprecated",
+            "cfg",
+            "allow",
+            "warn",
+            "deny",
+            "forbid",
+            "expect",
+        ];
+        attrs
+            .iter()
+            .filter(|attr| !HANDLED.iter().any(|handled| attr.path.is_ident(handled)))
+            .cloned()
+            .collect()
+    }
+
+    /// Renders attributes that must be applied to both the trait and the blanket impl.
+    fn render_item_attrs(&self) -> TokenStream2 {
+        let cfg_attrs = &self.cfg_attrs;
+        let lint_attrs = &self.lint_attrs;
+        let option_attrs = self.options.render_item_attrs();
+        quote! {
+            #(#cfg_attrs)*
+            #(#lint_attrs)*
+            #option_attrs
+        }
+    }
+
+    /// Renders trait alias into a new trait with bounds set.
+    fn render(self, options: &InvocationOptions) -> TokenStream2 {
+        let doc_comment = self.render_doc(options);
+        #[cfg(feature = "extras")]
+        let doc_comment = {
+            let on_unimplemented = self.render_on_unimplemented(options);
+            quote! {
+                #doc_comment
+                #on_unimplemented
+            }
+        };
+        #[cfg(feature = "extras")]
+        let bounds_str = self.render_bounds_str(options);
+        #[cfg(not(feature = "extras"))]
+        let bounds_str = TokenStream2::new();
+        let dyn_aliases = self.render_dyn_aliases();
+        let object_safety_check = self.render_object_safety_check();
+        let assertions = self.render_assertions();
+        let bounds_macro = self.render_bounds_macro();
+        // Generic and non-generic implementation have slightly different
+        // syntax, so it's simpler to process them individually rather than
+        // try to generalize implementation.
+        let alias = if self.options.sealed {
+            self.render_sealed(doc_comment)
+        } else if cfg!(feature = "trait-alias")
+            && self.constness.is_none()
+            && self.unsafety.is_none()
+        {
+            self.render_native(doc_comment)
+        } else if self.generics.params.is_empty() {
+            self.render_non_generic(doc_comment)
+        } else {
+            self.render_generic(doc_comment)
+        };
+
+        quote! {
+            #alias
+            #bounds_str
+            #dyn_aliases
+            #object_safety_check
+            #assertions
+            #bounds_macro
+        }
+    }
+
+    /// Renders `dyn Alias` and `Box<dyn Alias>` type aliases, if requested.
+    /// The types have a lifetime parameter for the lifetime of the trait object,
+    /// followed by the generic parameters of the alias.
+    fn render_dyn_aliases(&self) -> TokenStream2 {
+        if self.options.dyn_alias.is_none() && self.options.box_alias.is_none() {
+            return TokenStream2::new();
+        }
+
+        // The lifetime must not clash with the lifetimes of the alias.
+        let lifetime = (b'a'..=b'z')
+            .map(|name| format!("'{}", name as char))
+            .find(|name| {
+                !self
+                    .generics
+                    .lifetimes()
+                    .any(|param| param.lifetime.to_string() == *name)
+            })
+            .unwrap_or_else(|| String::from("'object"));
+        let lifetime = Lifetime::new(&lifetime, Span::call_site());
+        // Bounds of type aliases are not enforced, so only the names are kept.
+        let params = self.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                let default = param.default.as_ref().map(|default| quote! { = #default });
+                quote! { #ident #default }
+            }
+            GenericParam::Const(param) => {
+                let (ident, ty) = (&param.ident, &param.ty);
+                let default = param.default.as_ref().map(|default| quote! { = #default });
+                quote! { const #ident: #ty #default }
+            }
+        });
+        let generics = quote! { <#lifetime, #(#params),*> };
+        let alias_name = &self.alias_name;
+        let unbound_generics = self.unbound_generics();
+        let object = quote! { dyn #alias_name #unbound_generics + #lifetime };
+        let visibility = self
+            .options
+            .helpers_vis
+            .as_ref()
+            .unwrap_or(&self.visibility);
+        let deprecated = &self.deprecated;
+        let cfg_attrs = &self.cfg_attrs;
+
+        let dyn_alias = self.options.dyn_alias.as_ref().map(|name| {
+            let doc = format!("Trait object of [`{}`] alias.", alias_name);
+            quote! {
+                #[doc = #doc]
+                #deprecated
+                #(#cfg_attrs)*
+                #visibility type #name #generics = #object;
+            }
+        });
+        let box_alias = self.options.box_alias.as_ref().map(|name| {
+            let doc = format!("Boxed trait object of [`{}`] alias.", alias_name);
+            quote! {
+                #[doc = #doc]
+                #deprecated
+                #(#cfg_attrs)*
+                #visibility type #name #generics = ::std::boxed::Box<#object>;
+            }
+        });
+        quote! {
+            #dyn_alias
+            #box_alias
+        }
+    }
+
+    /// Renders a check that the alias can be used as a trait object, if requested,
+    /// so that bounds breaking it are reported at the alias declaration.
+    fn render_object_safety_check(&self) -> TokenStream2 {
+        if !self.options.assert_object_safe {
+            return TokenStream2::new();
+        }
+        let bound_generics = self.impl_generics();
+        let unbound_generics = self.unbound_generics();
+        // Predicates on `Self` can't be repeated outside of the trait, and they are
+        // implied by the trait object type anyway.
+        let predicates = self
+            .generics
+            .where_clause
+            .iter()
+            .flat_map(|where_clause| where_clause.predicates.iter())
+            .filter(|predicate| match predicate {
+                WherePredicate::Type(predicate) => {
+                    !mentions_self(predicate.bounded_ty.to_token_stream())
+                }
+                _ => true,
+            });
+        let alias_name = &self.alias_name;
+        let object = quote_spanned! { alias_name.span()=>
+            dyn #alias_name #unbound_generics
+        };
+        let allow_deprecated = self.render_allow_deprecated();
+        let cfg_attrs = &self.cfg_attrs;
+        quote! {
+            #allow_deprecated
+            #(#cfg_attrs)*
+            const _: () = {
+                fn _assert_object_safe<#bound_generics>(_: &#object) where #(#predicates,)* {}
+            };
+        }
+    }
+
+    /// Renders the declaration of the alias for any lifetimes requested via `hrtb_alias`
+    /// option, e.g. `trait AnyParser = for<'a> Parser<'a>;`. Other generic parameters
+    /// and the where clause are kept as is.
+    fn render_hrtb_alias(&self) -> Result<Option<TokenStream2>> {
+        let name = match &self.options.hrtb_alias {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let alias_name = &self.alias_name;
+        let lifetimes: Vec<_> = self.generics.lifetimes().collect();
+        if lifetimes.is_empty() {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "TS0028: `hrtb_alias` requires alias `{}` to have lifetime parameters",
+                    alias_name
+                ),
+            ));
+        }
+        // Lifetimes of higher-ranked trait bounds can't have bounds.
+        if let Some(lifetime) = lifetimes
+            .iter()
+            .find(|lifetime| !lifetime.bounds.is_empty())
+        {
+            return Err(Error::new(
+                lifetime.span(),
+                format!(
+                    "TS0028: Lifetime parameters of alias `{}` with `hrtb_alias` can't have bounds",
+                    alias_name
+                ),
+            ));
+        }
+        let lifetimes: Vec<_> = lifetimes
+            .iter()
+            .map(|lifetime| &lifetime.lifetime)
+            .collect();
+        let params = self
+            .generics
+            .params
+            .iter()
+            .filter(|param| !matches!(param, GenericParam::Lifetime(_)));
+        // The lifetimes are only introduced for the bounds of the alias, so other
+        // parameters and the where clause can't refer to them.
+        let mentioned = params
+            .clone()
+            .map(ToTokens::to_token_stream)
+            .chain(
+                self.generics
+                    .where_clause
+                    .as_ref()
+                    .map(ToTokens::to_token_stream),
+            )
+            .find_map(|tokens| find_lifetime(tokens, &lifetimes));
+        if let Some(span) = mentioned {
+            return Err(Error::new(
+                span,
+                format!(
+                    "TS0028: Lifetime parameters of alias `{}` with `hrtb_alias` can only be used \
+                     in its bounds, not in other generic parameters or the where clause",
+                    alias_name
+                ),
+            ));
+        }
+        let unbound_generics = self.unbound_generics();
+        let where_clause = &self.generics.where_clause;
+        let visibility = &self.visibility;
+        let cfg_attrs = &self.cfg_attrs;
+        let deprecated = self.deprecated.as_ref().map(|deprecated| {
+            quote! {
+                #deprecated
+                #[trait_set(allow_deprecated_bounds)]
+            }
+        });
+        let doc = format!("Alias for [`{}`] with any lifetimes.", alias_name);
+        Ok(Some(quote! {
+            #[doc = #doc]
+            #deprecated
+            #(#cfg_attrs)*
+            #visibility trait #name<#(#params),*> =
+                for<#(#lifetimes),*> #alias_name #unbound_generics #where_clause
+        }))
+    }
+
+    /// Renders the macro expanding to the alias bounds, if requested, so that they can be
+    /// reused in other places, including other crates:
+    ///
+    /// - `NAME!(dyn)` and `NAME!(impl)` expand to `dyn Bounds` and `impl Bounds` types.
+    /// - `NAME!(path::to::macro { before } { after })` invokes the macro with the bounds
+    ///   between the tokens, e.g. to declare another alias with `trait_set!`.
+    ///
+    /// The macro is exported from the crate if the alias is `pub`, and `crate::` paths in
+    /// the bounds are replaced with `$crate::`.
+    fn render_bounds_macro(&self) -> TokenStream2 {
+        let name = match &self.options.bounds_macro {
+            Some(name) => name,
+            None => return TokenStream2::new(),
+        };
+        let bounds = replace_crate(self.traits.bounds.to_token_stream());
+        let visibility = self
+            .options
+            .helpers_vis
+            .as_ref()
+            .unwrap_or(&self.visibility);
+        let export = match visibility {
+            Visibility::Public(_) => quote! { #[macro_export] },
+            _ => quote! { #[allow(unused_macros)] },
+        };
+        let doc = format!("Expands to the bounds of `{}` alias.", self.alias_name);
+        let deprecated = &self.deprecated;
+        let cfg_attrs = &self.cfg_attrs;
+        quote! {
+            #[doc = #doc]
+            #deprecated
+            #(#cfg_attrs)*
+            #export
+            macro_rules! #name {
+                (dyn) => { dyn #bounds };
+                (impl) => { impl #bounds };
+                ($callback:path { $($before:tt)* } $({ $($after:tt)* })?) => {
+                    $callback! { $($before)* #bounds $($($after)*)? }
+                };
+            }
+        }
+    }
+
+    /// Renders `assert_impl_Alias!` macro, if requested, checking that the listed types
+    /// implement the alias, e.g. `assert_impl_Alias!(u8, String);`. Arguments of generic
+    /// aliases follow the types after a semicolon: `assert_impl_Items!(Vec<u8>; u8);`.
+    fn render_assertions(&self) -> TokenStream2 {
+        if !self.options.assertions {
+            return TokenStream2::new();
+        }
+        let alias_name = &self.alias_name;
+        let name = Ident::new(
+            &format!(
+                "assert_impl_{}",
+                alias_name.to_string().trim_start_matches("r#")
+            ),
+            alias_name.span(),
+        );
+        let (pattern, bound) = if self.generics.params.is_empty() {
+            (quote! {}, quote! { #alias_name })
+        } else {
+            (quote! { ; $($args:tt)+ }, quote! { #alias_name<$($args)+> })
+        };
+        let allow_deprecated = self.render_allow_deprecated();
+        let cfg_attrs = &self.cfg_attrs;
+        let doc = format!(
+            "Checks that the listed types implement [`{}`] alias.",
+            alias_name
+        );
+        quote! {
+            #[doc = #doc]
+            #(#cfg_attrs)*
+            #[allow(unused_macros)]
+            macro_rules! #name {
+                ($($ty:ty),+ $(,)? #pattern) => {
+                    #allow_deprecated
+                    const _: () = {
+                        fn _assert<_T: ?Sized + #bound>() {}
+                        fn _check() {
+                            $(_assert::<$ty>();)+
+                        }
+                    };
+                };
+            }
+        }
+    }
+
+    /// Renders the string constant with the alias bounds, if requested.
+    #[cfg(feature = "extras")]
+    fn render_bounds_str(&self, options: &InvocationOptions) -> TokenStream2 {
+        let name = match &self.options.bounds_str {
+            Some(name) => name,
+            None => return TokenStream2::new(),
+        };
+        let visibility = self
+            .options
+            .helpers_vis
+            .as_ref()
+            .unwrap_or(&self.visibility);
+        let deprecated = &self.deprecated;
+        let cfg_attrs = &self.cfg_attrs;
+        let bounds = render_bounds(&self.traits.bounds);
+        let doc_comment = self.render_generated_doc(
+            options
+                .bounds_str_doc
+                .as_deref()
+                .unwrap_or("Bounds of [`{alias}`] alias as a string."),
+        );
+        quote! {
+            #doc_comment
+            #deprecated
+            #(#cfg_attrs)*
+            #visibility const #name: &str = #bounds;
+        }
+    }
+
+    /// Renders the generated doc-comment from a template with `{alias}` and `{bounds}`
+    /// placeholders. An empty template suppresses the doc-comment, so the `missing_docs`
+    /// lint is allowed instead.
+    #[cfg(feature = "extras")]
+    fn render_generated_doc(&self, template: &str) -> TokenStream2 {
+        if template.is_empty() {
+            return quote! { #[allow(missing_docs)] };
+        }
+        let doc_comment = template
+            .replace("{alias}", &self.alias_name.to_string())
+            .replace("{bounds}", &render_bounds(&self.traits.bounds));
+        quote! { #[doc = #doc_comment] }
+    }
+
+    /// Renders the doc-comment of the trait.
+    /// If the alias has no doc-comment, invocation options decide how to
+    /// satisfy the `missing_docs` lint.
+    fn render_doc(&self, options: &InvocationOptions) -> TokenStream2 {
+        #[cfg(feature = "extras")]
+        let (bounds_doc, params_doc, nightly_doc) = (
+            self.render_bounds_doc(),
+            self.render_params_doc(),
+            self.render_nightly_doc(options),
+        );
+        #[cfg(not(feature = "extras"))]
+        let (bounds_doc, params_doc, nightly_doc) = (
+            TokenStream2::new(),
+            TokenStream2::new(),
+            TokenStream2::new(),
+        );
+        let doc_attrs = &self.doc_attrs;
+        let doc = match (self.is_documented(), options.missing_docs) {
+            (true, _) | (false, None) => TokenStream2::new(),
+            (false, Some(MissingDocs::Allow)) => quote! { #[allow(missing_docs)] },
+            // Without `extras` feature there is no doc generation, so the lint is allowed.
+            #[cfg(not(feature = "extras"))]
+            (false, Some(MissingDocs::Generate)) => quote! { #[allow(missing_docs)] },
+            #[cfg(feature = "extras")]
+            (false, Some(MissingDocs::Generate)) => self.render_generated_doc(
+                options
+                    .generated_doc
+                    .as_deref()
+                    .unwrap_or("Alias for `{bounds}`."),
+            ),
+        };
+        quote! {
+            #(#doc_attrs)*
+            #doc
+            #bounds_doc
+            #params_doc
+            #nightly_doc
+        }
+    }
+
+    /// Renders the equivalent declaration using nightly `trait_alias` feature,
+    /// if requested, to simplify migration once the feature is stabilized.
+    #[cfg(feature = "extras")]
+    fn render_nightly_doc(&self, options: &InvocationOptions) -> TokenStream2 {
+        if !options.nightly_equivalent {
+            return TokenStream2::new();
+        }
+        let visibility = &self.visibility;
+        let visibility = match visibility {
+            Visibility::Inherited => String::new(),
+            _ => render_tokens(quote! { #visibility }) + " ",
+        };
+        let generics = &self.generics;
+        let where_clause = match &generics.where_clause {
+            Some(where_clause) => format!(" {}", render_tokens(quote! { #where_clause })),
+            None => String::new(),
+        };
+        let doc = format!(
+            "\n# Nightly equivalent\n\n```rust,ignore\n#![feature(trait_alias)]\n\n{}trait {}{} = {}{};\n```",
+            visibility,
+            self.alias_name,
+            render_tokens(quote! { #generics }),
+            render_bounds(&self.traits.bounds),
+            where_clause,
+        );
+        quote! { #[doc = #doc] }
+    }
+
+    /// Renders `#[diagnostic::on_unimplemented]` attribute naming the alias bounds, if
+    /// requested, so that errors hint at the underlying traits. The attribute written
+    /// by the user takes precedence.
+    #[cfg(feature = "extras")]
+    fn render_on_unimplemented(&self, options: &InvocationOptions) -> TokenStream2 {
+        let is_written = self.forwarded_attrs.iter().any(|attr| {
+            let path = &attr.path.segments;
+            path.len() == 2 && path[0].ident == "diagnostic" && path[1].ident == "on_unimplemented"
+        });
+        if !options.on_unimplemented || is_written {
+            return TokenStream2::new();
+        }
+        // Braces are placeholders in the messages, e.g. `{Self}`.
+        let escape = |text: String| text.replace('{', "{{").replace('}', "}}");
+        let alias_name = escape(self.alias_name.to_string());
+        let message = format!("`{{Self}}` doesn't implement `{}`", alias_name);
+        let note = format!(
+            "`{}` is an alias for `{}`, one of these bounds is not satisfied",
+            alias_name,
+            escape(render_bounds(&self.traits.bounds)),
+        );
+        quote! {
+            #[diagnostic::on_unimplemented(message = #message, note = #note)]
+        }
+    }
+
+    /// Renders the bounds of the alias after its doc-comment, unless the alias has no
+    /// doc-comment (generated ones already mention the bounds) or it's opted out via
+    /// `no_bounds_doc` option.
+    #[cfg(feature = "extras")]
+    fn render_bounds_doc(&self) -> TokenStream2 {
+        if !self.is_documented() || self.options.no_bounds_doc {
+            return TokenStream2::new();
+        }
+        let doc = format!("\nAlias for: `{}`", render_bounds(&self.traits.bounds));
+        quote! { #[doc = #doc] }
+    }
+
+    /// Renders doc-comments of generic parameters as a list, if there are any.
+    #[cfg(feature = "extras")]
+    fn render_params_doc(&self) -> TokenStream2 {
+        if self.param_docs.is_empty() {
+            return TokenStream2::new();
+        }
+        let mut doc = String::from("\n# Parameters\n");
+        for (name, param_doc) in &self.param_docs {
+            // Continuation lines are indented to stay within the list item.
+            let param_doc = param_doc.trim().replace('\n', "\n  ");
+            doc += &format!("\n- `{}`: {}", name, param_doc);
+        }
+        quote! { #[doc = #doc] }
+    }
+
+    /// Renders the lint attribute for the items that refer to the alias,
+    /// so that the deprecation only fires at the use sites.
+    fn render_allow_deprecated(&self) -> Option<TokenStream2> {
+        self.deprecated
+            .as_ref()
+            .map(|_| quote! { #[allow(deprecated)] })
+    }
+
+    /// Renders the native trait alias, available on nightly with `trait_alias` feature.
+    fn render_native(self, doc_comment: TokenStream2) -> TokenStream2 {
+        let item_attrs = self.render_item_attrs();
+        let bounds = self.traits.bounds;
+        let deprecated = self.deprecated;
+        let forwarded_attrs = self.forwarded_attrs;
+        let visibility = self.visibility;
+        let alias_name = self.alias_name;
+        let generics = self.generics;
+        let where_clause = &generics.where_clause;
+        quote! {
+            #doc_comment
+            #deprecated
+            #(#forwarded_attrs)*
+            #item_attrs
+            #visibility trait #alias_name #generics = #bounds #where_clause;
+        }
+    }
+
+    /// Renders `#[diagnostic::do_not_recommend]` attribute for the blanket impl with
+    /// `do-not-recommend` feature, so that errors name the alias rather than walk
+    /// through the blanket impl.
+    fn render_do_not_recommend() -> TokenStream2 {
+        if cfg!(feature = "do-not-recommend") {
+            quote! { #[diagnostic::do_not_recommend] }
+        } else {
+            TokenStream2::new()
+        }
+    }
+
+    /// Renders the trait alias without generic parameters.
+    fn render_non_generic(self, doc_comment: TokenStream2) -> TokenStream2 {
+        let item_attrs = self.render_item_attrs();
+        let allow_deprecated = self.render_allow_deprecated();
+        let bounds = self.render_alias_bounds();
+        let predicates = self.render_where_predicates();
+        let do_not_recommend = Self::render_do_not_recommend();
+        let impl_param = self.impl_param();
+        // `Self` in the bounds refers to the implementor in the blanket impl.
+        let (impl_bounds, impl_predicates) = (
+            replace_self(bounds.clone(), &impl_param),
+            replace_self(predicates, &impl_param),
+        );
+        let deprecated = self.deprecated;
+        let forwarded_attrs = self.forwarded_attrs;
+        let visibility = self.visibility;
+        let constness = self.constness;
+        let unsafety = self.unsafety;
+        let alias_name = self.alias_name;
+        let where_clause = self.generics.where_clause;
+        quote! {
+            #doc_comment
+            #deprecated
+            #(#forwarded_attrs)*
+            #item_attrs
+            #visibility #constness #unsafety trait #alias_name: #bounds #where_clause {}
+
+            #allow_deprecated
+            #item_attrs
+            #do_not_recommend
+            #unsafety impl<#impl_param: ?Sized> #constness #alias_name for #impl_param where #impl_param: #impl_bounds, #impl_predicates {}
+        }
+    }
+
+    /// Renders the trait alias with generic parameters.
+    fn render_generic(self, doc_comment: TokenStream2) -> TokenStream2 {
+        let item_attrs = self.render_item_attrs();
+        let allow_deprecated = self.render_allow_deprecated();
+        let unbound_generics = self.unbound_generics();
+        let impl_generics = self.impl_generics();
+        let bounds = self.render_alias_bounds();
+        let predicates = self.render_where_predicates();
+        let do_not_recommend = Self::render_do_not_recommend();
+        let impl_param = self.impl_param();
+        // `Self` in the bounds refers to the implementor in the blanket impl.
+        let (impl_bounds, impl_predicates) = (
+            replace_self(bounds.clone(), &impl_param),
+            replace_self(predicates, &impl_param),
+        );
+        let deprecated = self.deprecated;
+        let forwarded_attrs = self.forwarded_attrs;
+        let visibility = self.visibility;
+        let constness = self.constness;
+        let unsafety = self.unsafety;
+        let alias_name = self.alias_name;
+        let where_clause = self.generics.where_clause;
+        let bound_generics = self.generics.params;
+
+        // Note that it's important for the implementor to go *after* user-defined
+        // generics, because generics can contain lifetimes, and lifetimes
+        // should always go first.
+        quote! {
+            #doc_comment
+            #deprecated
+            #(#forwarded_attrs)*
+            #item_attrs
+            #visibility #constness #unsafety trait #alias_name<#bound_generics>: #bounds #where_clause {}
+
+            #allow_deprecated
+            #item_attrs
+            #do_not_recommend
+            #unsafety impl<#impl_generics, #impl_param: ?Sized> #constness #alias_name #unbound_generics for #impl_param where #impl_param: #impl_bounds, #impl_predicates {}
+        }
+    }
+
+    /// Renders the sealed alias: the trait has a private `Sealed` supertrait and no
+    /// blanket impl, so it's implemented only via `impl_Alias!` macro.
+    fn render_sealed(self, doc_comment: TokenStream2) -> TokenStream2 {
+        let (module, sealed_module) = render_sealed_module(&self.alias_name);
+        let sealed_macro = self.render_sealed_macro();
+        let item_attrs = self.render_item_attrs();
+        let bounds = self.render_alias_bounds();
+        let deprecated = self.deprecated;
+        let forwarded_attrs = self.forwarded_attrs;
+        let cfg_attrs = self.cfg_attrs;
+        let visibility = self.visibility;
+        let unsafety = self.unsafety;
+        let alias_name = self.alias_name;
+        let where_clause = self.generics.where_clause;
+        quote! {
+            #doc_comment
+            #deprecated
+            #(#forwarded_attrs)*
+            #item_attrs
+            #visibility #unsafety trait #alias_name: #bounds + #module::Sealed #where_clause {}
+
+            #(#cfg_attrs)*
+            #sealed_module
+
+            #sealed_macro
+        }
+    }
+
+    /// Renders `impl_Alias!` macro implementing the sealed alias for the listed types,
+    /// e.g. `impl_Alias!(u8, String);`, or the macro named via `sealed = "NAME"` option.
+    /// Types of unsafe aliases must be prefixed with `unsafe` in the macro call, e.g.
+    /// `impl_Alias!(unsafe u8)`, same as unsafe impls.
+    fn render_sealed_macro(&self) -> TokenStream2 {
+        let unsafety = &self.unsafety;
+        let alias_name = &self.alias_name;
+        let name = self.options.sealed_macro.clone().unwrap_or_else(|| {
+            Ident::new(
+                &format!("impl_{}", alias_name.to_string().trim_start_matches("r#")),
+                alias_name.span(),
+            )
+        });
+        let module = render_sealed_module(alias_name).0;
+        let allow_deprecated = self.render_allow_deprecated();
+        let item_attrs = self.render_item_attrs();
+        let cfg_attrs = &self.cfg_attrs;
+        let doc = format!(
+            "Implements sealed [`{}`] alias for the listed types.",
+            alias_name
+        );
+        quote! {
+            #[doc = #doc]
+            #(#cfg_attrs)*
+            #[allow(unused_macros)]
+            macro_rules! #name {
+                (#unsafety $($ty:ty),+ $(,)?) => {
+                    $(
+                        #item_attrs
+                        impl #module::Sealed for $ty {}
+
+                        #allow_deprecated
+                        #item_attrs
+                        #unsafety impl #alias_name for $ty {}
+                    )+
+                };
+            }
+        }
+    }
+
+    /// Renders the predicates of the where clause, if any, to be added to the where clause
+    /// of the blanket impl.
+    fn render_where_predicates(&self) -> TokenStream2 {
+        match &self.generics.where_clause {
+            Some(where_clause) => where_clause.predicates.to_token_stream(),
+            None => TokenStream2::new(),
+        }
+    }
+
+    /// Creates an error for attributes at the end of the invocation
+    /// that have no alias to be attached to.
+    fn dangling_attrs_error(attrs: &[Attribute]) -> Error {
+        let mut errors = attrs.iter().map(|attr| {
+            Error::new_spanned(
+                attr,
+                "TS0007: Attribute or doc-comment is not followed by a trait alias",
+            )
+        });
+        let mut error = errors.next().expect("Attributes are not empty");
+        errors.for_each(|next| error.combine(next));
+        error
+    }
+
+    /// Parses the generic parameters of the alias, including the where clause before `=`.
+    fn parse_generics(input: ParseStream) -> Result<Generics> {
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+        Ok(generics)
+    }
+
+    /// Parses the `const` keyword of const trait aliases, e.g. `pub const trait Ops = Add;`.
+    fn parse_constness(input: ParseStream) -> Result<Option<Token![const]>> {
+        if !(input.peek(Token![const])
+            && (input.peek2(Token![trait]) || input.peek2(Token![unsafe])))
+        {
+            return Ok(None);
+        }
+        let constness: Token![const] = input.parse()?;
+        if cfg!(feature = "nightly") {
+            Ok(Some(constness))
+        } else {
+            Err(Error::new(
+                constness.span,
+                "TS0020: Const trait aliases require `nightly` feature of `trait-set`",
+            ))
+        }
+    }
+
+    /// Renders the bounds of the trait and the blanket impl.
+    /// Trait bounds of const aliases are const as well, e.g. `[const] Add`,
+    /// except for marker traits, which can't be const. If some bounds are marked
+    /// as const explicitly (`~const Add`), only these bounds are const.
+    fn render_alias_bounds(&self) -> TokenStream2 {
+        const MARKER_TRAITS: &[&str] = &["Copy", "Send", "Sized", "Sync", "Unpin"];
+
+        if self.constness.is_none() {
+            return self.traits.bounds.to_token_stream();
+        }
+        let is_marker = |path: &Path| {
+            let name = path.segments.last().map(|segment| &segment.ident);
+            name.into_iter()
+                .any(|name| MARKER_TRAITS.iter().any(|marker| name == marker))
+        };
+        let explicit = self
+            .traits
+            .bounds
+            .iter()
+            .any(|bound| const_bound_span(bound).is_some());
+        let bounds = self.traits.bounds.iter().map(|bound| match bound {
+            TypeParamBound::Trait(TraitBound {
+                lifetimes,
+                path,
+                modifier: TraitBoundModifier::None,
+                ..
+            }) if const_bound_span(bound).is_some() => {
+                let path = Path {
+                    leading_colon: path.leading_colon,
+                    segments: path.segments.iter().skip(1).cloned().collect(),
+                };
+                quote! { #lifetimes [const] #path }
+            }
+            TypeParamBound::Trait(TraitBound {
+                lifetimes,
+                path,
+                modifier: TraitBoundModifier::None,
+                ..
+            }) if !explicit && !is_marker(path) => quote! { #lifetimes [const] #path },
+            bound => quote! { #bound },
+        });
+        quote! { #(#bounds)+* }
+    }
+
+    /// Parses the `trait` keyword, reporting other kinds of items
+    /// that people sometimes put into the macro by mistake.
+    fn parse_trait_token(input: ParseStream) -> Result<Token![trait]> {
+        if input.peek(Token![use]) {
+            return Err(input.error("TS0002: `use` declarations must go before the aliases"));
+        }
+
+        let lookahead = input.lookahead1();
+        if lookahead.peek(Token![trait]) {
+            return input.parse();
+        }
+        if input.peek(Token![fn])
+            || input.peek(Token![impl])
+            || input.peek(Token![struct])
+            || input.peek(Token![enum])
+            || input.peek(Token![union])
+            || input.peek(Token![mod])
+            || input.peek(Token![type])
+            || input.peek(Token![const])
+            || input.peek(Token![static])
+            || input.peek(Token![extern])
+        {
+            return Err(input.error(
+                "TS0001: Only trait alias declarations are allowed inside `trait_set!`: \
+                 `trait Alias = Trait1 + Trait2;`",
+            ));
+        }
+        Err(lookahead.error())
+    }
+
+    /// Parses `=` before the bounds of the alias.
+    #[cfg_attr(not(feature = "extras"), allow(unused_variables))]
+    fn parse_eq_token(
+        input: ParseStream,
+        alias_name: &Ident,
+        generics: &Generics,
+    ) -> Result<Token![=]> {
+        // Supertrait syntax is a common slip, since aliases look like trait declarations.
+        if input.peek(Token![:]) && !input.peek(Token![::]) {
+            let colon: Token![:] = input.parse()?;
+            #[cfg(feature = "extras")]
+            let suggestion = {
+                let bounds = input
+                    .fork()
+                    .call(Punctuated::<TypeParamBound, Token![+]>::parse_separated_nonempty)
+                    .map_or_else(
+                        |_| String::from("Trait1 + Trait2"),
+                        |bounds| render_bounds(&bounds),
+                    );
+                format!(
+                    "trait {}{} = {};",
+                    alias_name,
+                    render_tokens(quote! { #generics }),
+                    bounds
+                )
+            };
+            #[cfg(not(feature = "extras"))]
+            let suggestion = format!("trait {} = Trait1 + Trait2;", alias_name);
+            return Err(Error::new(
+                colon.span,
+                format!(
+                    "TS0029: Bounds of an alias go after `=`, not `:`, did you mean `{}`?",
+                    suggestion
+                ),
+            ));
+        }
+        input.parse()
+    }
+
+    /// Parses the bounds of the alias, keeping the span of `@auto` shorthand
+    /// and the bounds under `#[cfg(...)]` separately.
+    fn parse_traits(&mut self, input: ParseStream) -> Result<()> {
+        // `impl Trait` is a common slip for people used to return-position `impl Trait`,
+        // so we report it explicitly rather than let it fail with a generic parse error.
+        if input.peek(Token![impl]) {
+            let impl_token: Token![impl] = input.parse()?;
+            return Err(Error::new(
+                impl_token.span,
+                "TS0004: `impl` is not allowed in trait alias bounds, remove it: `trait Alias = Trait1 + Trait2;`",
+            ));
+        }
+        let mut traits = TypeTraitObject {
+            dyn_token: input.parse()?,
+            bounds: Punctuated::new(),
+        };
+        let mut auto = None;
+        let mut cfg_bounds = Vec::new();
+        loop {
+            let attrs = input.call(Attribute::parse_outer)?;
+            if let Some(attr) = attrs.iter().find(|attr| !attr.path.is_ident("cfg")) {
+                return Err(Error::new_spanned(
+                    attr,
+                    "TS0030: Only `#[cfg(...)]` attributes are allowed on alias bounds",
+                ));
+            }
+            if !attrs.is_empty() {
+                cfg_bounds.push((attrs, self.parse_bound(input)?));
+            } else if input.peek(Token![@]) {
+                auto = Some(Self::parse_auto(input)?);
+            } else {
+                let bound = self.parse_bound(input)?;
+                // `?Sized` is not allowed in supertraits, and unsized types are covered
+                // by the blanket impl anyway.
+                let is_maybe = matches!(
+                    &bound,
+                    TypeParamBound::Trait(TraitBound {
+                        modifier: TraitBoundModifier::Maybe(_),
+                        ..
+                    })
+                );
+                if !is_maybe {
+                    if !traits.bounds.empty_or_trailing() {
+                        traits.bounds.push_punct(Default::default());
+                    }
+                    traits.bounds.push_value(bound);
+                }
+            }
+            if !input.peek(Token![+]) {
+                break;
+            }
+            input.parse::<Token![+]>()?;
+            // Trailing `+` is accepted, as in other bounds.
+            if input.is_empty() || input.peek(Token![;]) {
+                break;
+            }
+        }
+        let has_trait = traits
+            .bounds
+            .iter()
+            .any(|bound| matches!(bound, TypeParamBound::Trait(_)));
+        if !has_trait && auto.is_none() {
+            return Err(input.error(if cfg_bounds.is_empty() {
+                "at least one trait is required for an object type"
+            } else {
+                "at least one trait without `#[cfg(...)]` is required"
+            }));
+        }
+
+        // People coming from the where-clause syntax sometimes use commas.
+        if input.peek(Token![,]) {
+            let comma: Token![,] = input.parse()?;
+            return Err(Error::new(
+                comma.span,
+                "TS0005: Bounds are separated with `+`, not `,`: `trait Alias = Trait1 + Trait2;`",
+            ));
+        }
+        self.traits = traits;
+        self.auto = auto;
+        self.cfg_bounds = cfg_bounds;
+        Ok(())
+    }
+
+    /// Parses a bound of the alias. Bounds can be marked as const with `~const Trait`
+    /// or `[const] Trait`, which makes the alias a const trait alias.
+    fn parse_bound(&mut self, input: ParseStream) -> Result<TypeParamBound> {
+        let bound = if input.peek(token::Bracket) {
+            let content;
+            let bracket = bracketed!(content in input);
+            let const_token: Token![const] = content.parse()?;
+            content.parse::<Nothing>()?;
+            // Kept the same way syn parses `~const Trait`.
+            let mut bound: TraitBound = input.parse()?;
+            bound
+                .path
+                .segments
+                .insert(0, PathSegment::from(Ident::new("const", const_token.span)));
+            if let Pair::Punctuated(_, punct) = bound.path.segments.pairs_mut().next().unwrap() {
+                *punct = Token![::](bracket.span);
+            }
+            TypeParamBound::Trait(bound)
+        } else {
+            input.parse()?
+        };
+        if let Some(span) = const_bound_span(&bound) {
+            if !cfg!(feature = "nightly-const") {
+                return Err(Error::new(
+                    span,
+                    "TS0020: `~const` bounds require `nightly-const` feature of `trait-set`",
+                ));
+            }
+            if self.constness.is_none() {
+                self.constness = Some(Token![const](span));
+            }
+        }
+        Ok(bound)
+    }
+
+    /// Parses `@auto` shorthand and returns its span.
+    fn parse_auto(input: ParseStream) -> Result<Span> {
+        let at: Token![@] = input.parse()?;
+        let name: Ident = input.parse()?;
+        if name != "auto" {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "TS0024: Unknown shorthand `@{}` in alias bounds, expected `@auto`",
+                    name
+                ),
+            ));
+        }
+        Ok(at.span.join(name.span()).unwrap_or(at.span))
+    }
+
+    /// Checks that the alias doesn't refer to itself by its name in the bounds, e.g.
+    /// `trait Iterator = Iterator<Item = u8>;`, which is a cycle rather than a reference
+    /// to the shadowed trait. Qualified paths (`std::iter::Iterator`) are reported by
+    /// `shadowed_bound` lint instead.
+    #[cfg(feature = "extras")]
+    fn check_shadowing(&self) -> Result<()> {
+        for bound in &self.traits.bounds {
+            if let TypeParamBound::Trait(bound) = bound {
+                if bound.path.leading_colon.is_some() || bound.path.segments.len() != 1 {
+                    continue;
+                }
+                let segment = &bound.path.segments[0];
+                if segment.ident == self.alias_name {
+                    return Err(Error::new(
+                        segment.ident.span(),
+                        format!(
+                            "TS0008: Alias `{0}` refers to itself in its bounds, use the full path \
+                             of the shadowed trait or rename the alias, e.g. to `{0}Alias`",
+                            self.alias_name
+                        ),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that generic parameters of the alias have unique names, so that
+    /// the error points at the duplicate rather than at the whole invocation.
+    #[cfg(feature = "extras")]
+    fn check_duplicate_generics(&self) -> Result<()> {
+        // The explicitly set implementor parameter is declared in the generated items as well.
+        let mut names: Vec<_> = self
+            .options
+            .impl_param
+            .iter()
+            .map(Ident::to_string)
+            .collect();
+        for param in &self.generics.params {
+            let (name, span) = match param {
+                GenericParam::Lifetime(lifetime) => {
+                    (lifetime.lifetime.to_string(), lifetime.lifetime.span())
+                }
+                GenericParam::Type(ty) => (ty.ident.to_string(), ty.ident.span()),
+                GenericParam::Const(constant) => {
+                    (constant.ident.to_string(), constant.ident.span())
+                }
+            };
+            if names.contains(&name) {
+                return Err(Error::new(
+                    span,
+                    format!(
+                        "TS0009: Generic parameter `{}` is already declared for alias `{}`",
+                        name, self.alias_name
+                    ),
+                ));
+            }
+            names.push(name);
+        }
+        Ok(())
+    }
+
+    /// Takes doc-comments out of the generic parameters, since the compiler ignores
+    /// them there, so that they can be rendered into the trait documentation.
+    fn take_param_docs(&mut self) -> Result<()> {
+        for param in self.generics.params.iter_mut() {
+            let (name, attrs) = match param {
+                GenericParam::Lifetime(lifetime) => {
+                    (lifetime.lifetime.to_string(), &mut lifetime.attrs)
+                }
+                GenericParam::Type(ty) => (ty.ident.to_string(), &mut ty.attrs),
+                GenericParam::Const(constant) => (constant.ident.to_string(), &mut constant.attrs),
+            };
+            let (docs, others): (Vec<_>, Vec<_>) = std::mem::take(attrs)
+                .into_iter()
+                .partition(|attr| attr.path.is_ident("doc"));
+            *attrs = others;
+            if let Some(doc) = Self::parse_doc(&docs)? {
+                self.param_docs.push((name, doc));
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces every `_` in the bounds with a new generic parameter of the alias,
+    /// e.g. `trait AnyIter = Iterator<Item = _>;` becomes
+    /// `trait AnyIter<__I0> = Iterator<Item = __I0>;`.
+    fn lift_wildcards(&mut self) -> Result<()> {
+        // Only the bounds are lifted: in other places a new parameter would change
+        // the meaning of the declared generics.
+        let mut misplaced = Vec::new();
+        for param in &self.generics.params {
+            if let GenericParam::Type(param) = param {
+                lift_wildcard_tokens(param.bounds.to_token_stream(), &mut misplaced);
+            }
+        }
+        if let Some(where_clause) = &self.generics.where_clause {
+            lift_wildcard_tokens(where_clause.to_token_stream(), &mut misplaced);
+        }
+        if let Some(wildcard) = misplaced.first() {
+            return Err(Error::new(
+                wildcard.span(),
+                format!(
+                    "TS0031: `_` can only be used in the bounds of alias `{}`, \
+                     not in bounds of its generic parameters or the where clause",
+                    self.alias_name
+                ),
+            ));
+        }
+        let mut params = Vec::new();
+        let traits = lift_wildcard_tokens(self.traits.to_token_stream(), &mut params);
+        if !params.is_empty() {
+            self.traits = syn::parse2(traits)?;
+        }
+        for (_, bound) in &mut self.cfg_bounds {
+            let count = params.len();
+            let lifted = lift_wildcard_tokens(bound.to_token_stream(), &mut params);
+            if params.len() > count {
+                *bound = syn::parse2(lifted)?;
+            }
+        }
+        // Parameters with defaults must be trailing, so the new ones go before them.
+        let position = self
+            .generics
+            .params
+            .iter()
+            .position(|param| match param {
+                GenericParam::Type(param) => param.default.is_some(),
+                GenericParam::Const(param) => param.default.is_some(),
+                GenericParam::Lifetime(_) => false,
+            })
+            .unwrap_or_else(|| self.generics.params.len());
+        for (index, param) in params.into_iter().enumerate() {
+            self.generics
+                .params
+                .insert(position + index, parse_quote! { #param });
+        }
+        Ok(())
+    }
+
+    /// Reorders generic parameters so that lifetimes go first, as required by
+    /// Rust. Relative order of parameters of the same kind is preserved.
+    fn normalize_generics(&mut self) {
+        let (lifetimes, others): (Vec<_>, Vec<_>) = std::mem::take(&mut self.generics.params)
+            .into_iter()
+            .partition(|param| matches!(param, GenericParam::Lifetime(_)));
+        self.generics.params = lifetimes.into_iter().chain(others).collect();
+    }
+
+    /// Returns the type parameter of the blanket impl standing for the implementor.
+    /// Unless set via `impl_param` option, it's `_INNER` with underscores appended
+    /// until it doesn't clash with the generic parameters of the alias.
+    fn impl_param(&self) -> Ident {
+        if let Some(name) = &self.options.impl_param {
+            return name.clone();
+        }
+        let mut name = String::from("_INNER");
+        while self.generics.params.iter().any(|param| match param {
+            GenericParam::Type(ty) => ty.ident == name,
+            GenericParam::Const(constant) => constant.ident == name,
+            GenericParam::Lifetime(_) => false,
+        }) {
+            name.push('_');
+        }
+        Ident::new(&name, Span::call_site())
+    }
+
+    /// Returns the generic parameters of the alias with trait bounds removed,
+    /// suitable for use as arguments of the alias trait.
+    fn unbound_generics(&self) -> TokenStream2 {
+        // We differentiate `generics` and `bound_generics` because in the
+        // `impl<X> Trait<Y>` block there must be only generic arguments in the `<Y>` part,
+        // while bounds (including `?Sized` and lifetime bounds) and `const` parameter types
+        // must go into `<X>` part only.
+        // E.g. `impl<'a, 'b: 'a, X: ?Sized, const N: usize, _INNER> Trait<'a, 'b, X, N> for _INNER`.
+        let (_, unbound_generics, _) = self.generics.split_for_impl();
+        unbound_generics.to_token_stream()
+    }
+
+    /// Returns the generic parameters of the alias without defaults, e.g. `Rhs = Self`,
+    /// since defaults are only allowed on the trait, and not on the blanket impl.
+    fn impl_generics(&self) -> Punctuated<GenericParam, Token![,]> {
+        let mut params = self.generics.params.clone();
+        for param in params.iter_mut() {
+            match param {
+                GenericParam::Type(ty) => {
+                    ty.eq_token = None;
+                    ty.default = None;
+                }
+                GenericParam::Const(constant) => {
+                    constant.eq_token = None;
+                    constant.default = None;
+                }
+                GenericParam::Lifetime(_) => {}
+            }
+        }
+        params
+    }
+
+    /// Renders a compile-time check that the alias implies the provided bounds.
+    ///
+    /// The check is a function that is never called: it only has to type-check,
+    /// which is possible only if every implementor of the alias also satisfies
+    /// `required`.
+    fn render_requirement(&self, required: &Punctuated<TypeParamBound, Token![+]>) -> TokenStream2 {
+        #[cfg(feature = "static-assertions")]
+        if let Some(assertion) = self.render_static_requirement(required) {
+            return assertion;
+        }
+
+        // The alias name in the signature is re-spanned, otherwise rustc suggests
+        // adding bounds right into the alias declaration.
+        let alias_name = Ident::new(&self.alias_name.to_string(), Span::call_site());
+        let unbound_generics = self.unbound_generics();
+        let bound_generics = self.impl_generics();
+        let impl_param = self.impl_param();
+        // Same as for the blanket impl, the implementor must go after user-defined generics.
+        let generics = if bound_generics.is_empty() {
+            quote! { #impl_param: #alias_name }
+        } else {
+            quote! { #bound_generics, #impl_param: #alias_name #unbound_generics }
+        };
+        // The implementor is re-spanned, so that errors point at the alias declaration.
+        let implementor = Ident::new(&impl_param.to_string(), self.alias_name.span());
+        let check = quote_spanned! { self.alias_name.span()=>
+            _required::<#implementor>();
+        };
+        // `Self` in the where clause refers to the implementor.
+        let predicates = replace_self(self.render_where_predicates(), &impl_param);
+        let allow_deprecated = self.render_allow_deprecated();
+        let cfg_attrs = &self.cfg_attrs;
+
+        quote! {
+            #allow_deprecated
+            #(#cfg_attrs)*
+            const _: () = {
+                fn _assert_required<#generics>() where #predicates {
+                    fn _required<_T: #required>() {}
+                    #check
+                }
+            };
+        }
+    }
+
+    /// Renders the check via `static_assertions::assert_trait_sub_all!`, if it can be
+    /// expressed with it: the alias must not be generic, and the required bounds must
+    /// be plain trait paths.
+    #[cfg(feature = "static-assertions")]
+    fn render_static_requirement(
+        &self,
+        required: &Punctuated<TypeParamBound, Token![+]>,
+    ) -> Option<TokenStream2> {
+        if !self.generics.params.is_empty() {
+            return None;
+        }
+        let paths = trait_paths(required)?;
+        let alias_name = &self.alias_name;
+        let allow_deprecated = self.render_allow_deprecated();
+        let cfg_attrs = &self.cfg_attrs;
+
+        Some(quote! {
+            #allow_deprecated
+            #(#cfg_attrs)*
+            const _: () = {
+                ::static_assertions::assert_trait_sub_all!(#alias_name: #(#paths),*);
+            };
+        })
+    }
+}
+
+impl Parse for TraitSet {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attrs: Vec<Attribute> = input.call(Attribute::parse_outer)?;
+        let visibility = input.parse()?;
+        // Attributes are also accepted after the visibility, e.g. `pub #[cfg(unix)] trait`,
+        // since code generators don't always control the order.
+        attrs.extend(input.call(Attribute::parse_outer)?);
+        if input.is_empty() && !attrs.is_empty() {
+            return Err(Self::dangling_attrs_error(&attrs));
+        }
+
+        let mut options = AliasOptions::parse(&attrs)?;
+        // Bound groups (`set Common = Send + Sync;`) are inline aliases with a shorter syntax.
+        let (constness, unsafety, trait_token) = if input.peek(kw::set) {
+            let set_token: kw::set = input.parse()?;
+            if !matches!(visibility, Visibility::Inherited) {
+                return Err(Error::new(
+                    visibility.span(),
+                    "TS0022: Bound group can't have a visibility, since no items are generated for it",
+                ));
+            }
+            options.inline = true;
+            (None, None, Token![trait](set_token.span))
+        } else {
+            (
+                Self::parse_constness(input)?,
+                input.parse()?,
+                Self::parse_trait_token(input)?,
+            )
+        };
+
+        let alias_name: Ident = input.parse()?;
+        let generics = Self::parse_generics(input)?;
+        let eq_token = Self::parse_eq_token(input, &alias_name, &generics)?;
+
+        let mut result = TraitSet {
+            doc_attrs: attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("doc"))
+                .cloned()
+                .collect(),
+            param_docs: Vec::new(),
+            options,
+            lint_attrs: Self::parse_lint_attrs(&attrs),
+            deprecated: attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("deprecated"))
+                .cloned(),
+            cfg_attrs: attrs
+                .iter()
+                .filter(|attr| attr.path.is_ident("cfg"))
+                .cloned()
+                .collect(),
+            forwarded_attrs: Self::parse_forwarded_attrs(&attrs),
+            visibility,
+            constness,
+            unsafety,
+            _trait_token: trait_token,
+            alias_name,
+            generics,
+            _eq_token: eq_token,
+            traits: TypeTraitObject {
+                dyn_token: None,
+                bounds: Punctuated::new(),
+            },
+            auto: None,
+            cfg_bounds: Vec::new(),
+        };
+        result.parse_traits(input)?;
+
+        // The where clause can also follow the bounds, as in nightly trait aliases.
+        if input.peek(Token![where]) {
+            let where_clause: WhereClause = input.parse()?;
+            if result.generics.where_clause.is_some() {
+                return Err(Error::new(
+                    where_clause.where_token.span,
+                    "TS0003: Alias already has a where clause before `=`, merge them into one",
+                ));
+            }
+            result.generics.where_clause = Some(where_clause);
+        }
+        #[cfg(feature = "extras")]
+        {
+            result.check_shadowing()?;
+            result.check_duplicate_generics()?;
+        }
+        result.take_param_docs()?;
+        result.lift_wildcards()?;
+        result.normalize_generics();
+        Ok(result)
+    }
+}
+
+/// Names imported via `use` declarations at the top of the invocation.
+///
+/// Imports only affect the paths in the bounds of the aliases: every path
+/// starting with an imported name is replaced with the full path, so the
+/// surrounding module is not affected.
+#[cfg(feature = "extras")]
+#[derive(Default)]
+struct Imports {
+    paths: HashMap<String, Path>,
+}
+
+#[cfg(feature = "extras")]
+impl Imports {
+    /// Parses all the `use` declarations at the current position.
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut imports = Self::default();
+
+        while Self::peek_use(input) {
+            let item: ItemUse = input.parse()?;
+            if let Some(attr) = item.attrs.first() {
+                return Err(Error::new(
+                    attr.span(),
+                    "TS0010: Attributes are not supported on `use` declarations inside `trait_set!`",
+                ));
+            }
+            let prefix = Path {
+                leading_colon: item.leading_colon,
+                segments: Punctuated::new(),
+            };
+            imports.add_tree(prefix, &item.tree)?;
+        }
+
+        Ok(imports)
+    }
+
+    /// Checks whether a `use` declaration, possibly with attributes, goes next.
+    fn peek_use(input: ParseStream) -> bool {
+        let fork = input.fork();
+        fork.call(Attribute::parse_outer).is_ok() && fork.peek(Token![use])
+    }
+
+    fn add_tree(&mut self, mut prefix: Path, tree: &UseTree) -> Result<()> {
+        match tree {
+            UseTree::Path(path) => {
+                prefix.segments.push(path.ident.clone().into());
+                self.add_tree(prefix, &path.tree)
+            }
+            UseTree::Name(name) if name.ident == "self" => {
+                let ident = match prefix.segments.last() {
+                    Some(segment) => segment.ident.to_string(),
+                    None => {
+                        return Err(Error::new(
+                            name.ident.span(),
+                            "TS0012: Invalid `self` import",
+                        ))
+                    }
+                };
+                self.paths.insert(ident, prefix);
+                Ok(())
+            }
+            UseTree::Name(name) => {
+                prefix.segments.push(name.ident.clone().into());
+                self.paths.insert(name.ident.to_string(), prefix);
+                Ok(())
+            }
+            UseTree::Rename(rename) => {
+                prefix.segments.push(rename.ident.clone().into());
+                self.paths.insert(rename.rename.to_string(), prefix);
+                Ok(())
+            }
+            UseTree::Glob(glob) => Err(Error::new(
+                glob.star_token.span,
+                "TS0011: Glob imports are not supported inside `trait_set!`, import items by name",
+            )),
+            UseTree::Group(group) => {
+                for tree in &group.items {
+                    self.add_tree(prefix.clone(), tree)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "extras")]
+impl VisitMut for Imports {
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        // `const` segment of `~const Trait` bounds is kept in front of the path.
+        let skip = usize::from(path.segments.len() > 1 && path.segments[0].ident == "const");
+        if path.leading_colon.is_none() {
+            let first = &path.segments[skip];
+            if let Some(import) = self.paths.get(&first.ident.to_string()) {
+                // The imported name is replaced with the full path, keeping the
+                // generic arguments and the span of the original segment.
+                let mut segments: Punctuated<_, _> =
+                    path.segments.iter().take(skip).cloned().collect();
+                segments.extend(import.segments.iter().cloned());
+                let last = segments.last_mut().expect("Imports are never empty");
+                last.ident.set_span(first.ident.span());
+                last.arguments = first.arguments.clone();
+                segments.extend(path.segments.iter().skip(skip + 1).cloned());
+
+                path.leading_colon = import.leading_colon;
+                path.segments = segments;
+            }
+        }
+        visit_mut::visit_path_mut(self, path);
+    }
+}
+
+/// Replaces `_` types in the tokens with new generic parameters named `__I0`, `__I1`,
+/// etc., which are added to `params`. Const generic expressions in braces and `'_`
+/// lifetimes are kept as is.
+fn lift_wildcard_tokens(tokens: TokenStream2, params: &mut Vec<Ident>) -> TokenStream2 {
+    let mut lifted = TokenStream2::new();
+    let mut prev_is_quote = false;
+    for token in tokens {
+        let is_quote = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == '\'');
+        match token {
+            TokenTree::Ident(ident) if ident == "_" && !prev_is_quote => {
+                let param = Ident::new(&format!("__I{}", params.len()), ident.span());
+                params.push(param.clone());
+                lifted.extend(Some(TokenTree::Ident(param)));
+            }
+            TokenTree::Group(group) if group.delimiter() != Delimiter::Brace => {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    lift_wildcard_tokens(group.stream(), params),
+                );
+                new_group.set_span(group.span());
+                lifted.extend(Some(TokenTree::Group(new_group)));
+            }
+            token => lifted.extend(Some(token)),
+        }
+        prev_is_quote = is_quote;
+    }
+    lifted
+}
+
+/// Expands alias families: declarations with alternatives in braces, e.g.
+/// `pub trait {Read, Write}Job = Job + {Read, Write};`, become one declaration
+/// per alternative, `ReadJob` and `WriteJob` in this case.
+///
+/// Only braces with a top-level comma hold alternatives, so const generic
+/// arguments such as `{ N + 1 }` are not affected. Inner attributes of the
+/// invocation are kept in front of the expanded declarations.
+fn expand_families(tokens: TokenStream2) -> Result<TokenStream2> {
+    let mut tokens: Vec<_> = tokens.into_iter().collect();
+    let mut expanded = Vec::new();
+    while let [TokenTree::Punct(pound), TokenTree::Punct(bang), TokenTree::Group(group), ..] =
+        tokens.as_slice()
+    {
+        if pound.as_char() != '#'
+            || bang.as_char() != '!'
+            || group.delimiter() != Delimiter::Bracket
+        {
+            break;
+        }
+        expanded.extend(tokens.drain(..3));
+    }
+
+    let mut declaration = Vec::new();
+    for token in tokens {
+        let is_end = matches!(&token, TokenTree::Punct(punct) if punct.as_char() == ';');
+        declaration.push(token);
+        if is_end {
+            expanded.extend(expand_family(std::mem::take(&mut declaration))?);
+        }
+    }
+    expanded.extend(expand_family(declaration)?);
+    Ok(expanded.into_iter().collect())
+}
+
+/// Expands a single declaration, see [`expand_families`].
+fn expand_family(declaration: Vec<TokenTree>) -> Result<Vec<TokenTree>> {
+    // Braces of `use` declarations, e.g. `use std::{fmt, io};`, are not alternatives.
+    let first_ident = declaration.iter().find_map(|token| match token {
+        TokenTree::Ident(ident) => Some(ident),
+        _ => None,
+    });
+    if matches!(first_ident, Some(ident) if ident == "use") {
+        return Ok(declaration);
+    }
+
+    Ok(match count_alternatives(&declaration)? {
+        Some(count) => (0..count)
+            .flat_map(|index| select_alternative(&declaration, index))
+            .collect(),
+        None => declaration,
+    })
+}
+
+/// Returns the number of alternatives in the declaration, if there are any,
+/// checking that all the braces have the same number of them.
+fn count_alternatives(tokens: &[TokenTree]) -> Result<Option<usize>> {
+    let mut count = None;
+    for token in tokens {
+        let group = match token {
+            TokenTree::Group(group) => group,
+            _ => continue,
+        };
+        let group_count = match split_alternatives(group) {
+            Some(alternatives) => Some(alternatives.len()),
+            // Alternatives may be nested into parentheses, e.g. `Fn({u8, u16})`.
+            None if group.delimiter() == Delimiter::Parenthesis => {
+                count_alternatives(&group.stream().into_iter().collect::<Vec<_>>())?
+            }
+            None => None,
+        };
+        match (count, group_count) {
+            (Some(count), Some(group_count)) if count != group_count => {
+                return Err(Error::new(
+                    group.span(),
+                    format!(
+                        "TS0023: Expected {} alternatives, as in the previous braces \
+                         of the alias family, found {}",
+                        count, group_count
+                    ),
+                ));
+            }
+            (None, group_count) => count = group_count,
+            _ => {}
+        }
+    }
+    Ok(count)
+}
+
+/// Splits the contents of braces by top-level commas, if there are any.
+fn split_alternatives(group: &Group) -> Option<Vec<Vec<TokenTree>>> {
+    if group.delimiter() != Delimiter::Brace {
+        return None;
+    }
+
+    let mut alternatives = vec![Vec::new()];
+    // Commas of generic arguments, e.g. in `{HashMap<K, V>, Vec<V>}`, are skipped.
+    let mut depth = 0usize;
+    let mut prev_dash = false;
+    for token in group.stream() {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                ',' if depth == 0 => {
+                    alternatives.push(Vec::new());
+                    prev_dash = false;
+                    continue;
+                }
+                '<' => depth += 1,
+                // `->` of `Fn() -> T` is not a closing angle bracket.
+                '>' if !prev_dash => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+        prev_dash = matches!(
+            &token,
+            TokenTree::Punct(punct) if punct.as_char() == '-' && punct.spacing() == Spacing::Joint
+        );
+        alternatives
+            .last_mut()
+            .expect("There is at least one alternative")
+            .push(token);
+    }
+    // Trailing comma, e.g. `{Read, Write,}`.
+    if matches!(alternatives.last(), Some(last) if last.is_empty()) {
+        alternatives.pop();
+    }
+
+    if alternatives.len() > 1 {
+        Some(alternatives)
+    } else {
+        None
+    }
+}
+
+/// Renders the declaration taking the alternative at `index` from every braces.
+/// Identifiers taken from the alternatives are glued to the adjacent identifiers,
+/// e.g. `{Read, Write}Job` becomes `ReadJob`, unless those are keywords.
+fn select_alternative(tokens: &[TokenTree], index: usize) -> Vec<TokenTree> {
+    const KEYWORDS: &[&str] = &[
+        "as", "const", "crate", "dyn", "for", "in", "pub", "self", "super", "trait", "where",
+    ];
+    let is_glued = |token: Option<&TokenTree>| match token {
+        Some(TokenTree::Ident(ident)) => !KEYWORDS.iter().any(|keyword| ident == keyword),
+        _ => false,
+    };
+
+    let mut selected: Vec<TokenTree> = Vec::new();
+    // Whether the last token is an identifier taken from the alternatives.
+    let mut after_alternative = false;
+    for token in tokens {
+        let mut alternative = match token {
+            TokenTree::Group(group) => match split_alternatives(group) {
+                Some(alternatives) => alternatives,
+                None => {
+                    after_alternative = false;
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        let stream = group.stream().into_iter().collect::<Vec<_>>();
+                        let mut selected_group = Group::new(
+                            Delimiter::Parenthesis,
+                            select_alternative(&stream, index).into_iter().collect(),
+                        );
+                        selected_group.set_span(group.span());
+                        selected.push(selected_group.into());
+                    } else {
+                        selected.push(token.clone());
+                    }
+                    continue;
+                }
+            },
+            _ => {
+                if after_alternative && is_glued(Some(token)) && is_glued(selected.last()) {
+                    glue(&mut selected, token);
+                } else {
+                    selected.push(token.clone());
+                }
+                after_alternative = false;
+                continue;
+            }
+        };
+
+        let mut alternative = alternative.swap_remove(index).into_iter();
+        if let Some(first) = alternative.next() {
+            if is_glued(Some(&first)) && is_glued(selected.last()) {
+                glue(&mut selected, &first);
+            } else {
+                selected.push(first);
+            }
+        }
+        selected.extend(alternative);
+        after_alternative = matches!(selected.last(), Some(TokenTree::Ident(_)));
+    }
+    selected
+}
+
+/// Appends the identifier to the last identifier of the tokens.
+fn glue(tokens: &mut [TokenTree], ident: &TokenTree) {
+    if let Some(last) = tokens.last_mut() {
+        *last = Ident::new(&format!("{}{}", last, ident), ident.span()).into();
+    }
+}
+
+/// Represents a sequence of trait aliases delimited by semicolon.
+struct ManyTraitSet {
+    /// Bounds that every alias in the invocation must imply,
+    /// collected from `#![require(...)]` attributes.
+    requirements: Vec<Punctuated<TypeParamBound, Token![+]>>,
+    options: InvocationOptions,
+    entries: Punctuated<TraitSet, Token![;]>,
+}
+
+impl Parse for ManyTraitSet {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let inner_attrs = input.call(Attribute::parse_inner)?;
+        #[cfg_attr(not(feature = "extras"), allow(unused_mut))]
+        let (mut requirements, options) = Self::parse_inner_attrs(&inner_attrs)?;
+        #[cfg(feature = "extras")]
+        let mut imports = Imports::parse(input)?;
+        #[cfg(not(feature = "extras"))]
+        Self::reject_imports(input)?;
+        let entries = Self::expand_hrtb_aliases(Self::parse_entries(input)?)?;
+        let mut entries = Self::expand_cfg_bounds(entries)?;
+        for entry in entries.iter_mut() {
+            if let Some(span) = entry.auto {
+                entry.traits.bounds.extend(options.auto_traits(span));
+            }
+        }
+        Self::expand_inline_aliases(&mut entries)?;
+        Self::check_sealed_aliases(&entries)?;
+
+        #[cfg(feature = "extras")]
+        if !imports.paths.is_empty() {
+            for required in &mut requirements {
+                for bound in required.iter_mut() {
+                    imports.visit_type_param_bound_mut(bound);
+                }
+            }
+            for entry in entries.iter_mut() {
+                imports.visit_generics_mut(&mut entry.generics);
+                imports.visit_type_trait_object_mut(&mut entry.traits);
+            }
+        }
+        #[cfg(feature = "extras")]
+        for entry in &entries {
+            if !entry.options.inline {
+                options.check_docs(entry)?;
+            }
+            options.check_forbidden(entry)?;
+        }
+
+        Ok(ManyTraitSet {
+            requirements,
+            options,
+            entries,
+        })
+    }
+}
+
+impl ManyTraitSet {
+    /// `use` declarations are not supported without `extras` feature.
+    #[cfg(not(feature = "extras"))]
+    fn reject_imports(input: ParseStream) -> Result<()> {
+        let fork = input.fork();
+        fork.call(Attribute::parse_outer)?;
+        if fork.peek(Token![use]) {
+            let use_token: Token![use] = fork.parse()?;
+            return Err(Error::new(
+                use_token.span,
+                "TS0020: `use` declarations require `extras` feature of `trait-set`",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Parses the aliases delimited by semicolon.
+    fn parse_entries(input: ParseStream) -> Result<Punctuated<TraitSet, Token![;]>> {
+        let mut entries = Punctuated::new();
+
+        while !input.is_empty() {
+            let entry: TraitSet = input.parse()?;
+            if input.is_empty() {
+                entries.push_value(entry);
+                break;
+            }
+
+            // If the next alias starts right after the bounds, the semicolon is most likely
+            // forgotten. Otherwise the error would point at the start of the next alias.
+            if !input.peek(Token![;])
+                && (input.peek(Token![pub])
+                    || input.peek(Token![trait])
+                    || input.peek(Token![unsafe])
+                    || input.peek(kw::set)
+                    || input.peek(Token![#]))
+            {
+                let bounds = &entry.traits.bounds;
+                let last_token = quote! { #bounds }.into_iter().last();
+                let span = last_token.map_or_else(|| entry.alias_name.span(), |token| token.span());
+                return Err(Error::new(
+                    span,
+                    format!("TS0006: Missing `;` after alias `{}`", entry.alias_name),
+                ));
+            }
+
+            let semicolon = input.parse()?;
+            entries.push_value(entry);
+            entries.push_punct(semicolon);
+        }
+
+        Ok(entries)
+    }
+
+    /// Adds the aliases requested via `hrtb_alias` option after the aliases they're based on.
+    fn expand_hrtb_aliases(
+        entries: Punctuated<TraitSet, Token![;]>,
+    ) -> Result<Punctuated<TraitSet, Token![;]>> {
+        let mut expanded = Punctuated::new();
+        for entry in entries {
+            let hrtb_alias = entry.render_hrtb_alias()?;
+            expanded.push(entry);
+            if let Some(hrtb_alias) = hrtb_alias {
+                expanded.push(syn::parse2(hrtb_alias)?);
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// Replaces aliases with bounds or generic parameters under `#[cfg(...)]` with their
+    /// variants for every combination of the conditions, each applied under the matching
+    /// `#[cfg(...)]`. Generic parameters can't be conditional by themselves, since generic
+    /// arguments of the alias in the blanket impl can't have attributes.
+    fn expand_cfg_bounds(
+        entries: Punctuated<TraitSet, Token![;]>,
+    ) -> Result<Punctuated<TraitSet, Token![;]>> {
+        // Each condition doubles the number of variants.
+        const MAX_CONDITIONS: usize = 6;
+
+        let mut expanded = Punctuated::new();
+        for mut entry in entries {
+            let cfg_bounds = std::mem::take(&mut entry.cfg_bounds);
+            let mut cfg_params = Vec::new();
+            for (position, param) in entry.generics.params.iter_mut().enumerate() {
+                let attrs = match param {
+                    GenericParam::Lifetime(lifetime) => &mut lifetime.attrs,
+                    GenericParam::Type(ty) => &mut ty.attrs,
+                    GenericParam::Const(constant) => &mut constant.attrs,
+                };
+                let (cfg, others): (Vec<_>, Vec<_>) = std::mem::take(attrs)
+                    .into_iter()
+                    .partition(|attr| attr.path.is_ident("cfg"));
+                *attrs = others;
+                if !cfg.is_empty() {
+                    cfg_params.push((cfg, position));
+                }
+            }
+            let first_attrs = cfg_params
+                .first()
+                .map(|(attrs, _)| attrs)
+                .or_else(|| cfg_bounds.first().map(|(attrs, _)| attrs));
+            let first_attr = match first_attrs {
+                Some(attrs) => attrs[0].clone(),
+                None => {
+                    expanded.push(entry);
+                    continue;
+                }
+            };
+            if entry.options.sealed {
+                return Err(Error::new_spanned(
+                    first_attr,
+                    format!(
+                        "TS0027: Sealed alias `{}` can't have bounds or generic parameters under `#[cfg(...)]`",
+                        entry.alias_name
+                    ),
+                ));
+            }
+
+            // Bounds and parameters under the same attributes share the condition.
+            let mut conditions: Vec<(String, TokenStream2)> = Vec::new();
+            let mut condition_index = |attrs: &[Attribute]| -> Result<usize> {
+                let predicates = attrs
+                    .iter()
+                    .map(|attr| attr.parse_args::<TokenStream2>())
+                    .collect::<Result<Vec<_>>>()?;
+                let condition = quote! { all(#(#predicates),*) };
+                let key = condition.to_string();
+                Ok(
+                    match conditions.iter().position(|(known, _)| *known == key) {
+                        Some(index) => index,
+                        None => {
+                            conditions.push((key, condition));
+                            conditions.len() - 1
+                        }
+                    },
+                )
+            };
+            let mut params = Vec::new();
+            for (attrs, position) in &cfg_params {
+                params.push((condition_index(attrs)?, *position));
+            }
+            let mut bounds = Vec::new();
+            for (attrs, bound) in cfg_bounds {
+                bounds.push((condition_index(&attrs)?, bound));
+            }
+            if conditions.len() > MAX_CONDITIONS {
+                return Err(Error::new_spanned(
+                    first_attr,
+                    format!(
+                        "TS0030: Alias `{}` has more than {} distinct conditions on its bounds \
+                         and generic parameters",
+                        entry.alias_name, MAX_CONDITIONS
+                    ),
+                ));
+            }
+
+            for variant in 0..1usize << conditions.len() {
+                let enabled = |index: usize| variant & (1 << index) != 0;
+                let mut alias = entry.clone();
+                alias.generics.params = entry
+                    .generics
+                    .params
+                    .iter()
+                    .enumerate()
+                    .filter(|(position, _)| {
+                        params
+                            .iter()
+                            .all(|(index, param)| param != position || enabled(*index))
+                    })
+                    .map(|(_, param)| param.clone())
+                    .collect();
+                for (index, bound) in &bounds {
+                    if enabled(*index) {
+                        alias.traits.bounds.push(bound.clone());
+                    }
+                }
+                let predicates = conditions
+                    .iter()
+                    .enumerate()
+                    .map(|(index, (_, condition))| {
+                        if enabled(index) {
+                            condition.clone()
+                        } else {
+                            quote! { not(#condition) }
+                        }
+                    });
+                let span = first_attr.pound_token.span;
+                alias
+                    .cfg_attrs
+                    .push(parse_quote_spanned! { span=> #[cfg(all(#(#predicates),*))] });
+                expanded.push(alias);
+            }
+        }
+        Ok(expanded)
+    }
+
+    /// Checks that sealed aliases (`#[trait_set(sealed)]`) can be implemented via
+    /// `impl_Alias!` macro.
+    fn check_sealed_aliases(entries: &Punctuated<TraitSet, Token![;]>) -> Result<()> {
+        for entry in entries.iter().filter(|entry| entry.options.sealed) {
+            let error = |span: Span, reason: &str| {
+                Err(Error::new(
+                    span,
+                    format!(
+                        "TS0027: Sealed alias `{}` can't {}",
+                        entry.alias_name, reason
+                    ),
+                ))
+            };
+            if entry.options.inline {
+                return error(entry.alias_name.span(), "be inline");
+            }
+            if let Some(constness) = &entry.constness {
+                return error(constness.span, "be const");
+            }
+            if let Some(param) = entry.generics.params.first() {
+                return error(param.span(), "have generic parameters");
+            }
+        }
+        Ok(())
+    }
+
+    /// Replaces references to inline aliases (`#[trait_set(inline)]`) with their bounds.
+    fn expand_inline_aliases(entries: &mut Punctuated<TraitSet, Token![;]>) -> Result<()> {
+        let mut inline = HashMap::new();
+        for entry in entries.iter().filter(|entry| entry.options.inline) {
+            if let Some(param) = entry.generics.params.first() {
+                return Err(Error::new(
+                    param.span(),
+                    format!(
+                        "TS0022: Inline alias `{}` can't have generic parameters",
+                        entry.alias_name
+                    ),
+                ));
+            }
+            if let Some(attr) = entry.cfg_attrs.first() {
+                return Err(Error::new(
+                    attr.span(),
+                    format!(
+                        "TS0022: Inline alias `{}` can't have `#[cfg(...)]` attributes, \
+                         since it's written out unconditionally",
+                        entry.alias_name
+                    ),
+                ));
+            }
+            if let Some(where_clause) = &entry.generics.where_clause {
+                return Err(Error::new(
+                    where_clause.where_token.span,
+                    format!(
+                        "TS0022: Inline alias `{}` can't have a where clause",
+                        entry.alias_name
+                    ),
+                ));
+            }
+            let checks = [
+                ("assert_object_safe", entry.options.assert_object_safe),
+                ("assertions", entry.options.assertions),
+            ];
+            if let Some((option, _)) = checks.iter().find(|(_, enabled)| *enabled) {
+                return Err(Error::new(
+                    entry.alias_name.span(),
+                    format!(
+                        "TS0022: Inline alias `{}` can't have `{}`, since no items are generated for it",
+                        entry.alias_name, option
+                    ),
+                ));
+            }
+            let companions = [
+                ("bounds_str", &entry.options.bounds_str),
+                ("bounds_macro", &entry.options.bounds_macro),
+                ("dyn_alias", &entry.options.dyn_alias),
+                ("box_alias", &entry.options.box_alias),
+                ("hrtb_alias", &entry.options.hrtb_alias),
+            ];
+            let companion = companions
+                .iter()
+                .find_map(|(option, name)| name.as_ref().map(|name| (option, name)));
+            if let Some((option, name)) = companion {
+                return Err(Error::new(
+                    name.span(),
+                    format!(
+                        "TS0022: Inline alias `{}` can't have `{}`, since no items are generated for it",
+                        entry.alias_name, option
+                    ),
+                ));
+            }
+            inline.insert(entry.alias_name.to_string(), entry.traits.bounds.clone());
+        }
+        if inline.is_empty() {
+            return Ok(());
+        }
+
+        let inline_name = |bound: &TypeParamBound| match bound {
+            TypeParamBound::Trait(TraitBound {
+                lifetimes: None,
+                modifier: TraitBoundModifier::None,
+                path,
+                ..
+            }) => path
+                .get_ident()
+                .map(Ident::to_string)
+                .filter(|name| inline.contains_key(name)),
+            _ => None,
+        };
+        for entry in entries.iter_mut() {
+            // Inline aliases may refer to each other, so the expansion is repeated:
+            // at most once per inline alias, unless they refer to each other in a loop.
+            for _ in 0..=inline.len() {
+                if !entry
+                    .traits
+                    .bounds
+                    .iter()
+                    .any(|bound| inline_name(bound).is_some())
+                {
+                    break;
+                }
+                entry.traits.bounds = entry
+                    .traits
+                    .bounds
+                    .iter()
+                    .flat_map(|bound| match inline_name(bound) {
+                        Some(name) => inline[&name].iter().cloned().collect(),
+                        None => vec![bound.clone()],
+                    })
+                    .collect();
+            }
+            // Const bounds of inline aliases make the alias const as well.
+            if entry.constness.is_none() {
+                entry.constness = entry
+                    .traits
+                    .bounds
+                    .iter()
+                    .find_map(const_bound_span)
+                    .map(Token![const]);
+            }
+            if let Some(bound) = entry
+                .traits
+                .bounds
+                .iter()
+                .find(|bound| inline_name(bound).is_some())
+            {
+                return Err(Error::new(
+                    bound.span(),
+                    format!(
+                        "TS0022: Inline alias `{}` refers to itself",
+                        inline_name(bound).unwrap_or_default()
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses the inner attributes of the invocation, e.g. `#![require(Send + Sync)]`.
+    #[allow(clippy::type_complexity)]
+    fn parse_inner_attrs(
+        attrs: &[Attribute],
+    ) -> Result<(
+        Vec<Punctuated<TypeParamBound, Token![+]>>,
+        InvocationOptions,
+    )> {
+        let mut requirements = Vec::new();
+        let mut options = InvocationOptions::from_env()?;
+
+        for attr in attrs {
+            if attr.path.is_ident("require") {
+                requirements.push(attr.parse_args_with(Punctuated::parse_separated_nonempty)?);
+            } else if attr.path.is_ident("trait_set") {
+                options.parse_attr(attr)?;
+            } else if let Some(level) = LintLevel::from_attr(attr) {
+                options.parse_lint_attr(attr, level)?;
+            } else {
+                return Err(Error::new(
+                    attr.path.span(),
+                    "TS0013: Unknown inner attribute, expected `#![require(...)]`, `#![trait_set(...)]` \
+                     or a lint attribute, e.g. `#![allow(trait_set::possible_typo)]`",
+                ));
+            }
+        }
+
+        Ok((requirements, options))
+    }
+
+    /// Maps the names of the aliases declared in the invocation to the aliases,
+    /// so that the checks don't look through all of them for every bound.
+    #[cfg(feature = "extras")]
+    fn aliases_by_name(&self) -> HashMap<String, &TraitSet> {
+        self.entries
+            .iter()
+            .map(|entry| (entry.alias_name.to_string(), entry))
+            .collect()
+    }
+
+    /// Looks for bounds that are likely misspelled names of other aliases
+    /// declared in the same invocation, e.g. `TreadSafe` instead of `ThreadSafe`.
+    #[cfg(feature = "extras")]
+    fn check_typos(&self, aliases: &HashMap<String, &TraitSet>) -> Vec<Warning> {
+        // The same bounds (e.g. `Send`) are usually repeated across the aliases,
+        // so the suggestions are only looked up once per name.
+        let mut suggestions: HashMap<String, Option<String>> = HashMap::new();
+        let mut warnings = Vec::new();
+
+        for entry in &self.entries {
+            for ident in entry.traits.bounds.iter().filter_map(bound_ident) {
+                let bound_name = ident.to_string();
+                if aliases.contains_key(&bound_name)
+                    || WELL_KNOWN_TRAITS.contains(&bound_name.as_str())
+                {
+                    continue;
+                }
+
+                let suggestion = suggestions
+                    .entry(bound_name)
+                    .or_insert_with_key(|bound_name| Self::suggest_alias(bound_name, aliases));
+                if let Some(suggestion) = suggestion {
+                    warnings.push(Warning {
+                        name: "possible_typo",
+                        message: format!(
+                            "`{}` is not declared in this `trait_set!` invocation, did you mean `{}`?",
+                            ident, suggestion
+                        ),
+                        suggestion: Some(suggestion.clone()),
+                        span: ident.span(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Finds the alias with the name closest to the given one, if it's close enough.
+    #[cfg(feature = "extras")]
+    fn suggest_alias(bound_name: &str, aliases: &HashMap<String, &TraitSet>) -> Option<String> {
+        // Allow roughly one typo per three characters, but at least one.
+        let max_distance = (bound_name.len() / 3).max(1);
+        let bound_len = bound_name.chars().count();
+        aliases
+            .keys()
+            // The distance is at least the difference of lengths, which is cheap to check.
+            .filter(|name| {
+                let len = name.chars().count();
+                len.max(bound_len) - len.min(bound_len) <= max_distance
+            })
+            .map(|name| (edit_distance(bound_name, name), name))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min()
+            .map(|(_, name)| name.clone())
+    }
+
+    /// Looks for aliases with the same name as one of their bounds, e.g.
+    /// `pub trait Error = std::error::Error + Send + Sync;`: the alias shadows the bound
+    /// wherever both are imported, which may be confusing.
+    #[cfg(feature = "extras")]
+    fn check_shadowed_bounds(&self) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for entry in &self.entries {
+            for bound in &entry.traits.bounds {
+                let segment = match bound {
+                    TypeParamBound::Trait(bound) => bound.path.segments.last(),
+                    TypeParamBound::Lifetime(_) => None,
+                };
+                if let Some(segment) = segment.filter(|segment| segment.ident == entry.alias_name) {
+                    warnings.push(Warning {
+                        name: "shadowed_bound",
+                        message: format!(
+                            "Alias `{}` has the same name as its bound and shadows it where both \
+                             are imported",
+                            entry.alias_name
+                        ),
+                        suggestion: None,
+                        span: segment.ident.span(),
+                    });
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Looks for `pub` aliases built from non-`pub` aliases declared in the same
+    /// invocation: downstream users would see a supertrait they can't name.
+    #[cfg(feature = "extras")]
+    fn check_visibility(&self, aliases: &HashMap<String, &TraitSet>) -> Vec<Warning> {
+        let mut warnings = Vec::new();
+
+        for entry in &self.entries {
+            if !matches!(entry.visibility, Visibility::Public(_)) {
+                continue;
+            }
+            for ident in entry.traits.bounds.iter().filter_map(bound_ident) {
+                let component = match aliases.get(&ident.to_string()) {
+                    Some(component) if !matches!(component.visibility, Visibility::Public(_)) => {
+                        component
+                    }
+                    _ => continue,
+                };
+
+                let component_visibility = match &component.visibility {
+                    Visibility::Inherited => "private".to_string(),
+                    visibility => format!("`{}`", render_tokens(quote! { #visibility })),
+                };
+                let bounds = render_bounds(&component.traits.bounds);
+                warnings.push(Warning {
+                    name: "private_component",
+                    message: format!(
+                        "`pub` alias `{}` is built from {} alias `{}`, which downstream users can't name; \
+                         consider replacing it with its bounds `{}` or making it `pub`",
+                        entry.alias_name,
+                        component_visibility,
+                        component.alias_name,
+                        bounds,
+                    ),
+                    suggestion: Some(bounds),
+                    span: ident.span(),
+                });
+            }
+        }
+
+        warnings
+    }
+
+    /// Looks for aliases with more bounds than allowed by `max_bounds` option.
+    /// Bounds of other aliases declared in the same invocation are counted
+    /// as if they were written out in place.
+    #[cfg(feature = "extras")]
+    fn check_bound_count(&self, aliases: &HashMap<String, &TraitSet>) -> Vec<Warning> {
+        let max_bounds = match self.options.max_bounds {
+            Some(max_bounds) => max_bounds,
+            None => return Vec::new(),
+        };
+
+        let counts = Self::count_bounds(aliases);
+        self.entries
+            .iter()
+            .filter_map(|entry| {
+                let count = counts[&entry.alias_name.to_string()];
+                if count <= max_bounds {
+                    return None;
+                }
+                Some(Warning {
+                    name: "too_many_bounds",
+                    message: format!(
+                        "Alias `{}` has {} bounds, while at most {} are allowed; \
+                         consider splitting it into smaller aliases",
+                        entry.alias_name, count, max_bounds
+                    ),
+                    suggestion: None,
+                    span: entry.alias_name.span(),
+                })
+            })
+            .collect()
+    }
+
+    /// Counts the bounds of every alias, expanding the aliases of the same invocation.
+    ///
+    /// The aliases are expanded with an explicit stack rather than recursion, and each
+    /// alias is counted once, so long chains of aliases don't blow up. An alias referring
+    /// to one being expanded (i.e. a recursive alias) is counted as a single bound.
+    #[cfg(feature = "extras")]
+    fn count_bounds(aliases: &HashMap<String, &TraitSet>) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let mut expanding = HashSet::new();
+
+        for name in aliases.keys() {
+            // The alias is counted once all of its components are.
+            let mut stack = vec![(name.clone(), false)];
+            while let Some((name, components_counted)) = stack.pop() {
+                if counts.contains_key(&name) {
+                    continue;
+                }
+                let components = aliases[&name]
+                    .traits
+                    .bounds
+                    .iter()
+                    .filter_map(bound_ident)
+                    .map(Ident::to_string)
+                    .filter(|component| aliases.contains_key(component));
+
+                if !components_counted {
+                    expanding.insert(name.clone());
+                    let pending: Vec<String> = components
+                        .filter(|component| {
+                            !counts.contains_key(component) && !expanding.contains(component)
+                        })
+                        .collect();
+                    stack.push((name, true));
+                    stack.extend(pending.into_iter().map(|component| (component, false)));
+                    continue;
+                }
+
+                let count = aliases[&name]
+                    .traits
+                    .bounds
+                    .iter()
+                    .map(|bound| {
+                        bound_ident(bound)
+                            .and_then(|ident| counts.get(&ident.to_string()))
+                            .copied()
+                            .unwrap_or(1)
+                    })
+                    .fold(0, usize::saturating_add);
+                expanding.remove(&name);
+                counts.insert(name, count);
+            }
+        }
+
+        counts
+    }
+
+    /// Looks for bounds that add nothing to the alias: `Sized` bounds of generic
+    /// parameters, repeated bounds and bounds implied by other bounds of the alias,
+    /// e.g. `Clone` next to `Copy`, or `Send` next to an alias of the invocation
+    /// that already includes it.
+    #[cfg(feature = "extras")]
+    fn check_redundant_bounds(&self, aliases: &HashMap<String, &TraitSet>) -> Vec<Warning> {
+        // Skip the lookups for the lint that is allowed by default.
+        if self.options.lint_level("redundant_bounds") == LintLevel::Allow {
+            return Vec::new();
+        }
+        let mut warnings = Vec::new();
+        let mut warn = |bound: &TypeParamBound, message: String| {
+            warnings.push(Warning {
+                name: "redundant_bounds",
+                message,
+                suggestion: None,
+                span: bound.span(),
+            });
+        };
+
+        for entry in &self.entries {
+            for param in entry.generics.type_params() {
+                for bound in param
+                    .bounds
+                    .iter()
+                    .filter(|bound| bound_key(bound) == "Sized")
+                {
+                    warn(
+                        bound,
+                        format!(
+                            "`Sized` bound of `{}` in alias `{}` is implied by default",
+                            param.ident, entry.alias_name
+                        ),
+                    );
+                }
+            }
+
+            let keys: Vec<String> = entry.traits.bounds.iter().map(bound_key).collect();
+            for (i, bound) in entry.traits.bounds.iter().enumerate() {
+                let key = &keys[i];
+                if keys[..i].contains(key) {
+                    warn(
+                        bound,
+                        format!("`{}` is repeated in alias `{}`", key, entry.alias_name),
+                    );
+                    continue;
+                }
+                let implying = entry
+                    .traits
+                    .bounds
+                    .iter()
+                    .zip(&keys)
+                    .find(|(other, other_key)| {
+                        *other_key != key && Self::implied_bounds(other, aliases).contains(key)
+                    });
+                if let Some((_, implying)) = implying {
+                    warn(
+                        bound,
+                        format!(
+                            "`{}` is implied by `{}` in alias `{}`",
+                            key, implying, entry.alias_name
+                        ),
+                    );
+                }
+            }
+        }
+
+        warnings
+    }
+
+    /// Collects the bounds implied by the bound: bounds of the aliases of the invocation
+    /// and supertraits of well-known standard library traits.
+    #[cfg(feature = "extras")]
+    fn implied_bounds(bound: &TypeParamBound, aliases: &HashMap<String, &TraitSet>) -> Vec<String> {
+        /// Standard library traits with their (transitive) supertraits.
+        const SUPERTRAITS: &[(&str, &[&str])] = &[
+            ("Copy", &["Clone"]),
+            ("Eq", &["PartialEq"]),
+            ("PartialOrd", &["PartialEq"]),
+            ("Ord", &["Eq", "PartialOrd", "PartialEq"]),
+            ("Error", &["Debug", "Display"]),
+            ("DoubleEndedIterator", &["Iterator"]),
+            ("ExactSizeIterator", &["Iterator"]),
+            ("FusedIterator", &["Iterator"]),
+        ];
+
+        let mut implied = Vec::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![bound];
+        while let Some(bound) = stack.pop() {
+            let key = bound_key(bound);
+            if let Some(alias) = aliases.get(&key) {
+                if visited.insert(key) {
+                    for component in &alias.traits.bounds {
+                        implied.push(bound_key(component));
+                        stack.push(component);
+                    }
+                }
+            } else if let Some((_, supertraits)) = SUPERTRAITS.iter().find(|(name, _)| *name == key)
+            {
+                implied.extend(supertraits.iter().map(|name| name.to_string()));
+            }
+        }
+
+        implied
+    }
+
+    /// Renders the warnings of the invocation according to their lint levels.
+    #[cfg(feature = "extras")]
+    fn render_warnings(&self) -> TokenStream2 {
+        let mut tokens = TokenStream2::new();
+        #[cfg(feature = "diagnostics-file")]
+        let mut diagnostics = Vec::new();
+
+        let aliases = self.aliases_by_name();
+        let warnings = self
+            .check_typos(&aliases)
+            .into_iter()
+            .chain(self.check_visibility(&aliases))
+            .chain(self.check_shadowed_bounds())
+            .chain(self.check_bound_count(&aliases))
+            .chain(self.check_redundant_bounds(&aliases));
+        // Variants of an alias with bounds under `#[cfg(...)]` may report the same warning.
+        let mut reported = HashSet::new();
+        for warning in warnings {
+            if !reported.insert((warning.name, warning.message.clone())) {
+                continue;
+            }
+            let level = self.options.lint_level(warning.name);
+            match level {
+                LintLevel::Allow => continue,
+                LintLevel::Deny => {
+                    let message = format!(
+                        "{} (denied by `#![deny(trait_set::{})]`)",
+                        warning.message, warning.name
+                    );
+                    tokens.extend(Error::new(warning.span, message).to_compile_error());
+                }
+                LintLevel::Warn => tokens.extend(warning.render()),
+            }
+            #[cfg(feature = "diagnostics-file")]
+            diagnostics.push(Diagnostic {
+                level: if level == LintLevel::Deny {
+                    "error"
+                } else {
+                    "warning"
+                },
+                code: warning.name.to_string(),
+                message: warning.message,
+                suggestion: warning.suggestion,
+                span: warning.span,
+            });
+        }
+
+        #[cfg(feature = "diagnostics-file")]
+        {
+            if let Err(error) = Diagnostic::write_all(&diagnostics) {
+                tokens.extend(error.to_compile_error());
+            }
+        }
+        tokens
+    }
+
+    fn render(self) -> TokenStream2 {
+        #[cfg(feature = "extras")]
+        let warnings = self.render_warnings();
+        #[cfg(not(feature = "extras"))]
+        let warnings = TokenStream2::new();
+        let requirements = self.requirements;
+        let options = self.options;
+        let entries = self
+            .entries
+            .into_iter()
+            .filter(|entry| !entry.options.inline);
+        let aliases = TokenStream2::from_iter(entries.map(|entry| {
+            let checks = TokenStream2::from_iter(
+                requirements
+                    .iter()
+                    .map(|required| entry.render_requirement(required)),
+            );
+            #[cfg(feature = "extras")]
+            let debug = if entry.options.debug {
+                Some(entry.alias_name.clone())
+            } else {
+                None
+            };
+            let alias = entry.render(&options);
+            let tokens = quote! {
+                #alias
+                #checks
+            };
+            #[cfg(feature = "extras")]
+            if let Some(alias_name) = debug {
+                let expansion = Warning {
+                    name: "expansion",
+                    message: format!(
+                        "Expansion of alias `{}`:\n{}",
+                        alias_name,
+                        render_items(tokens.clone())
+                    ),
+                    suggestion: None,
+                    span: alias_name.span(),
+                }
+                .render();
+                return quote! {
+                    #tokens
+                    #expansion
+                };
+            }
+            tokens
+        }));
+
+        quote! {
+            #aliases
+            #warnings
+        }
+    }
+}
+
+/// Creates an alias for set of traits.
+///
+/// To demonstrate the idea, see the examples:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     /// Doc-comments are also supported btw.
+///     pub trait ThreadSafe = Send + Sync;
+///     pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+///     pub trait ThreadSafeBytesIterator = ThreadSafeIterator<u8>;
+///     pub trait StaticDebug = 'static + std::fmt::Debug;
+/// }
+///```
+///
+/// This macro also supports [higher-rank trait bound][hrtb]:
+///
+/// ```rust
+/// # pub trait Serializer {
+/// #     type Ok;
+/// #     type Error;
+/// #
+/// #     fn ok_value() -> Self::Ok;
+/// # }
+/// # pub trait Deserializer<'de> {
+/// #     type Error;
+/// # }
+/// #
+/// # pub trait Serialize {
+/// #     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+/// #     where
+/// #         S: Serializer;
+/// # }
+/// #
+/// # pub trait Deserialize<'de>: Sized {
+/// #     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+/// #     where
+/// #         D: Deserializer<'de>;
+/// # }
+/// #
+/// # impl Serializer for u8 {
+/// #     type Ok = ();
+/// #     type Error = ();
+/// #
+/// #     fn ok_value() -> Self::Ok {
+/// #         ()
+/// #     }
+/// # }
+/// #
+/// # impl<'de> Deserializer<'de> for u8 {
+/// #     type Error = ();
+/// # }
+/// #
+/// # impl Serialize for u8 {
+/// #     fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
+/// #     where
+/// #         S: Serializer
+/// #     {
+/// #         Ok(S::ok_value())
+/// #     }
+/// # }
+/// #
+/// # impl<'de> Deserialize<'de> for u8 {
+/// #     fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
+/// #     where
+/// #         D: Deserializer<'de>
+/// #         {
+/// #             Ok(0u8)
+/// #         }
+/// # }
+/// use trait_set::trait_set;
+///
+/// trait_set!{
+///     pub trait Serde = Serialize + for<'de> Deserialize<'de>;
+///     // Note that you can also use lifetimes as a generic parameter.
+///     pub trait SerdeLifetimeTemplate<'de> = Serialize + Deserialize<'de>;
+/// }
+/// ```
+///
+/// Bounds of associated types can be set in place, e.g. `Iterator<Item: Debug>`
+/// (requires Rust 1.79 or newer and `extras` feature). Like supertraits, they are
+/// implied by the alias:
+///
+#[cfg_attr(feature = "extras", doc = "```rust")]
+#[cfg_attr(not(feature = "extras"), doc = "```ignore")]
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     pub trait DebugItems = Iterator<Item: std::fmt::Debug> + ExactSizeIterator;
+/// }
+///
+/// fn print<I: DebugItems>(items: I) {
+///     println!("{} items", items.len());
+///     for item in items {
+///         println!("{:?}", item);
+///     }
+/// }
+/// ```
+///
+/// Aliases are implemented for unsized types as well, e.g. `str` or `dyn Trait`,
+/// unless their bounds require `Sized`. `?Sized` in alias bounds is accepted for
+/// compatibility with nightly trait aliases, and has no effect.
+///
+/// Aliases can have a where clause, either before `=` or after the bounds, as in nightly
+/// trait aliases. Like for regular traits, only the predicates on `Self` are implied by
+/// the alias, others have to be repeated where the alias is used:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     pub trait Items<T> where T: Clone + Send = Iterator<Item = T>;
+///     pub trait Pairs<K, V> = Iterator<Item = (K, V)> where K: Ord;
+/// }
+/// ```
+///
+/// Doc-comments of generic parameters are rendered as a "Parameters" section
+/// of the alias documentation:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     /// Iterator over key-value pairs.
+///     pub trait Entries<
+///         /// The key type.
+///         K,
+///         /// The value type.
+///         V,
+///     > = Iterator<Item = (K, V)>;
+/// }
+/// ```
+///
+/// Types in the bounds can be replaced with `_`: every `_` becomes a new generic parameter
+/// of the alias, appended after the declared ones (but before the ones with defaults)
+/// and named `__I0`, `__I1`, etc. `_` in bounds of generic parameters or in the where
+/// clause is an error:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     // Same as `pub trait AnyIterator<__I0> = Iterator<Item = __I0> + Send;`
+///     pub trait AnyIterator = Iterator<Item = _> + Send;
+/// }
+///
+/// fn count<I: AnyIterator<T>, T>(iter: I) -> usize {
+///     iter.count()
+/// }
+/// ```
+///
+/// Generic parameters can have defaults, which may refer to the implementor via `Self`,
+/// e.g. for operator bound sets:
+///
+/// ```rust
+/// use std::ops::{Add, Sub};
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     pub trait Ops<Rhs = Self> = Add<Rhs, Output = Self> + Sub<Rhs, Output = Self>;
+/// }
+///
+/// fn add_sub<T: Ops + Copy>(a: T, b: T) -> T {
+///     a + b - b
+/// }
+/// ```
+///
+/// Invocation may start with `#![require(...)]` attributes, which make the compiler
+/// check that every declared alias implies the listed bounds:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #![require(Send + Sync)]
+///
+///     pub trait ThreadSafe = Send + Sync;
+///     pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+/// }
+/// ```
+///
+/// Aliases can be preceded by `use` declarations (requires `extras` feature). They only
+/// affect the paths in the alias bounds and don't import anything into the surrounding module:
+///
+#[cfg_attr(feature = "extras", doc = "```rust")]
+#[cfg_attr(not(feature = "extras"), doc = "```ignore")]
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     use std::fmt::{Debug, Display};
+///
+///     pub trait Printable = Debug + Display;
+/// }
+/// ```
+///
+/// `@auto` in alias bounds is a shorthand for the well-known auto traits: `Send + Sync +
+/// Unpin + UnwindSafe + RefUnwindSafe`, referred to via `core`, so it works in `no_std`
+/// crates (requires Rust 1.56 or newer). The set can be narrowed for the whole invocation
+/// with `#![trait_set(auto_traits(...))]` option:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #![trait_set(auto_traits(Send, Sync, Unpin))]
+///
+///     // Same as `pub trait Service = Clone + 'static + Send + Sync + Unpin;`
+///     pub trait Service = Clone + 'static + @auto;
+/// }
+/// ```
+///
+/// Families of similar aliases can be declared at once by listing the alternatives in
+/// braces: the declaration is repeated for each alternative, taking the first ones from
+/// all the braces, then the second ones, and so on. Identifiers from the alternatives are
+/// glued to the adjacent identifiers, so they can be used to build alias names:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     // Same as `pub trait ReadJob = Send + std::io::Read;` and
+///     // `pub trait WriteJob = Send + std::io::Write;`
+///     pub trait {Read, Write}Job = Send + std::io::{Read, Write};
+///     pub trait Into{U8, U16}<T> = Into<{u8, u16}> + From<T>;
+/// }
+/// ```
+///
+/// Note that the options of such declarations are repeated as well, so `bounds_str`
+/// would declare the same constant for each alias of the family.
+///
+/// Options for the whole invocation can be set via `#![trait_set(...)]` attribute.
+/// Supported options are:
+///
+/// - `missing_docs = "allow" | "generate"`: makes aliases without doc-comments
+///   compatible with `#![deny(missing_docs)]`, either by allowing the lint on the
+///   generated trait, or by generating a doc-comment from the alias bounds.
+/// - `require_docs`: rejects `pub` aliases without doc-comments.
+/// - `forbid(Trait1, path::to::Trait2, ...)`: rejects aliases that have any of the
+///   listed traits in their bounds, including the where clause, the bounds of generic
+///   parameters and of associated types (`Iterator<Item: Copy>`). Paths are matched by
+///   their trailing segments, so `forbid(Copy)` also rejects `std::marker::Copy`.
+/// - `max_bounds = N`: warns about aliases with more than `N` bounds. Bounds of other
+///   aliases declared in the same invocation are counted as if they were written out.
+/// - `generated_doc = "..."` and `bounds_str_doc = "..."`: override the doc-comments
+///   generated for aliases and for `bounds_str` constants respectively, e.g. to write
+///   them in another language. `{alias}` and `{bounds}` in the text are replaced with
+///   the alias name and bounds, and an empty text omits the doc-comment altogether.
+/// - `nightly_equivalent`: adds a "Nightly equivalent" section to the alias docs with
+///   the same alias declared using the unstable `trait_alias` feature, to simplify
+///   the migration once the feature is stabilized.
+/// - `auto_traits(Send, Sync, ...)`: sets the traits `@auto` shorthand expands to,
+///   see below.
+/// - `on_unimplemented`: adds `#[diagnostic::on_unimplemented]` attribute listing the alias
+///   bounds, so that errors hint at the underlying traits (requires Rust 1.78 or newer
+///   and `extras` feature). The attribute can also be written on an alias by hand, then
+///   it's used as is.
+///
+/// ```rust
+/// #![deny(missing_docs)]
+/// //! Crate documentation.
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #![trait_set(missing_docs = "generate")]
+///
+///     pub trait ThreadSafe = Send + Sync;
+/// }
+/// ```
+///
+/// Defaults for these options can be set via environment variables at build time,
+/// e.g. to tighten the checks on CI:
+///
+/// - `TRAIT_SET_MISSING_DOCS=allow|generate` sets the default for `missing_docs`.
+/// - `TRAIT_SET_REQUIRE_DOCS=true` enables `require_docs`.
+/// - `TRAIT_SET_FORBID=Copy,path::to::Trait` adds traits to `forbid`.
+/// - `TRAIT_SET_MAX_BOUNDS=N` sets the default for `max_bounds`.
+///
+/// Note that cargo doesn't track these variables, so changing them doesn't trigger
+/// recompilation of already built crates.
+///
+/// Warnings emitted by the macro can be controlled with inner lint attributes,
+/// e.g. `#![warn(trait_set::possible_typo)]` or `#![deny(trait_set::too_many_bounds)]`.
+/// Lints that can fire on valid input are allowed by default, so that the macro doesn't
+/// break crates built with `#![deny(warnings)]`. The lints are:
+///
+/// - `possible_typo` (allowed by default): a bound is likely a misspelled name of another
+///   alias of the invocation.
+/// - `private_component` (allowed by default): a `pub` alias is built from a non-`pub`
+///   alias of the invocation.
+/// - `redundant_bounds` (allowed by default): a bound adds nothing to the alias, e.g. `Sized`
+///   bound of a generic parameter, a repeated bound, `Clone` next to `Copy`, or `Send` next
+///   to an alias of the invocation that already includes it.
+/// - `shadowed_bound` (allowed by default): an alias has the same name as one of its
+///   bounds, e.g. `pub trait Error = std::error::Error + Send + Sync;`, and shadows it.
+/// - `too_many_bounds`: an alias has more bounds than allowed by `max_bounds` option,
+///   so it's only emitted if the option is set.
+///
+/// Aliases can be configured via `#[trait_set(...)]` attribute. Supported options are:
+///
+/// - `allow_deprecated_bounds`: suppress deprecation warnings caused by deprecated traits
+///   in the bounds of the alias.
+/// - `assert_object_safe`: check that the alias is [dyn compatible][dyn], so that bounds
+///   breaking it are reported at the alias declaration rather than where the trait object
+///   is used.
+/// - `assertions`: generate `assert_impl_Alias!` macro checking that the listed types
+///   implement the alias, e.g. to pin in tests that types keep satisfying it:
+///   `assert_impl_Alias!(u8, String);`. Arguments of generic aliases follow the types
+///   after a semicolon: `assert_impl_Items!(Vec<u8>; u8);`. Like `impl_Alias!` of sealed
+///   aliases, the macro must be used in the module of the invocation.
+/// - `bounds_macro = "NAME"`: generate `NAME!` macro expanding to the alias bounds, for
+///   places where an alias can't be used: `NAME!(dyn)` and `NAME!(impl)` expand to the
+///   trait object and `impl Trait` types, and `NAME!(path::to::macro { ... })` passes the
+///   bounds to another macro after the given tokens. The macro is exported if the alias
+///   is `pub` (or `helpers_vis = "pub"` is set), so the bounds should use absolute paths.
+///   Set `helpers_vis` to a restricted visibility to keep the macro of a `pub` alias local.
+/// - `bounds_str = "NAME"`: generate a `&str` constant with the alias bounds, e.g. to
+///   reuse them in code generators or to build bounds for derive attributes.
+/// - `helpers_vis = "VISIBILITY"`: visibility of the companion items, such as the
+///   `bounds_str` constant, e.g. `helpers_vis = "pub(crate)"` or `helpers_vis = ""`
+///   to keep them private while the alias is `pub`. Defaults to the visibility of the alias.
+/// - `debug`: report the items generated for the alias as a warning, e.g. to find out why
+///   a type doesn't satisfy the alias without expanding the whole crate.
+/// - `dyn_alias = "NAME"` and `box_alias = "NAME"`: generate type aliases for the trait
+///   object of the alias and for the boxed trait object, e.g. `NAME<'a> = dyn Alias + 'a`
+///   and `NAME<'a> = Box<dyn Alias + 'a>`. The lifetime parameter goes before the generic
+///   parameters of the alias. The alias must be [dyn compatible][dyn].
+/// - `hrtb_alias = "NAME"`: generate an alias of the alias for any values of its lifetime
+///   parameters, e.g. `#[trait_set(hrtb_alias = "AnyParser")]` on
+///   `trait Parser<'a> = Fn(&'a str);` adds `trait AnyParser = for<'a> Parser<'a>;`.
+///   Other generic parameters and the where clause are kept as is.
+/// - `impl_param = "NAME"`: name of the type parameter of the blanket impl standing for
+///   the implementor, as shown in the documentation and in compiler errors. Defaults to
+///   `_INNER`, with underscores appended if the alias has a generic parameter with this name.
+/// - `inline`: make the alias a building block for other aliases of the invocation: its
+///   bounds are written out in place of it, and no items are generated for it. Inline
+///   aliases can't have generic parameters.
+/// - `no_bounds_doc`: omit the "Alias for: `...`" line listing the alias bounds, which is
+///   otherwise appended to the doc-comment of the alias.
+/// - `sealed` or `sealed = "NAME"`: make the alias a [sealed trait][sealed]: instead of
+///   being implemented for every type satisfying the bounds, it's implemented only for
+///   the types listed in `impl_Alias!(Type, ...)` macro generated for the alias, or in
+///   `NAME!(Type, ...)` if the name is set. The macro must be used in the module of the
+///   invocation. Sealed aliases can't have generic parameters.
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #[trait_set(inline)]
+///     trait ThreadSafe = Send + Sync;
+///
+///     // Same as `pub trait SharedValue = Send + Sync + 'static + Clone;`
+///     pub trait SharedValue = ThreadSafe + 'static + Clone;
+/// }
+/// ```
+///
+/// Bound groups declared with `set` are a shorter syntax for inline aliases:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     set Common = Send + Sync + 'static;
+///
+///     pub trait Worker = Common + FnMut();
+/// }
+/// ```
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #[trait_set(sealed)]
+///     pub trait Key = Clone + std::fmt::Display;
+/// }
+///
+/// impl_Key!(u8, String);
+/// ```
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// #[deprecated]
+/// pub trait OldTrait {}
+///
+/// trait_set! {
+///     #[trait_set(allow_deprecated_bounds)]
+///     pub trait Migrating = OldTrait + Send;
+/// }
+/// ```
+///
+/// Aliases can be used in return position as is, e.g. `impl Alias` or, with `box_alias`,
+/// as a boxed trait object. No `type Name = impl Alias;` companion is generated, since
+/// such a type stands for a single concrete type rather than for any type implementing
+/// the alias:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #[trait_set(box_alias = "BoxedNumbers")]
+///     pub trait Numbers<T> = Iterator<Item = T> + Send;
+/// }
+///
+/// fn evens(limit: u32) -> impl Numbers<u32> {
+///     (0..limit).filter(|n| n % 2 == 0)
+/// }
+///
+/// fn numbers(even: bool) -> BoxedNumbers<'static, u32> {
+///     if even {
+///         Box::new(evens(10))
+///     } else {
+///         Box::new((0..10).filter(|n| n % 2 == 1))
+///     }
+/// }
+/// ```
+///
+/// Bounds of an alias can be conditional, e.g. to depend on cargo features. The alias
+/// is declared for every combination of the conditions, so it requires exactly the bounds
+/// whose conditions hold:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     pub trait Storage = std::io::Read + std::io::Write + #[cfg(unix)] std::io::Seek;
+/// }
+/// ```
+///
+/// Generic parameters can be conditional as well, e.g.
+/// `pub trait Store<#[cfg(feature = "keyed")] K> = Clone + #[cfg(feature = "keyed")] Index<K>;`.
+/// Other attributes of generic parameters are applied to both the trait and the blanket impl.
+///
+/// Aliases of unsafe traits can be declared as `unsafe trait`, so that the alias carries
+/// the same connotation, e.g. `pub unsafe trait PodSafe = Pod + Zeroable;`. The blanket
+/// impl is `unsafe` as well, and sealed unsafe aliases are implemented via
+/// `impl_Alias!(unsafe Type)`.
+///
+/// Doc-comments and other `#[doc ...]` attributes of an alias, e.g. `#[doc(hidden)]` or
+/// `#[doc(alias = "...")]`, are applied to the generated trait as is.
+/// Lint attributes of an alias (`#[allow(...)]`, `#[deny(...)]`, etc) are applied to
+/// the generated items. `#[deprecated]` is applied to the alias and its companion items,
+/// such as the `bounds_str` constant.
+/// `#[cfg(...)]` attributes are applied to all the generated items, so aliases can be
+/// feature-gated, and other attributes (e.g. `#[cfg_attr(docsrs, doc(cfg(...)))]`)
+/// are applied to the generated trait as is:
+///
+/// ```rust
+/// use trait_set::trait_set;
+///
+/// trait_set! {
+///     #[cfg(unix)]
+///     #[cfg_attr(docsrs, doc(cfg(unix)))]
+///     pub trait UnixStream = std::os::unix::io::AsRawFd + std::io::Read;
+/// }
+/// ```
+///
+/// [hrtb]: https://doc.rust-lang.org/nomicon/hrtb.html
+/// [dyn]: https://doc.rust-lang.org/reference/items/traits.html#dyn-compatibility
+/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
+#[proc_macro]
+pub fn trait_set(tokens: TokenStream) -> TokenStream {
+    let input = match expand_families(tokens.into()).and_then(syn::parse2::<ManyTraitSet>) {
+        Ok(input) => input,
+        Err(error) => {
+            #[cfg(feature = "diagnostics-file")]
+            let error = Diagnostic::write_error(error);
+            return error.to_compile_error().into();
+        }
+    };
+    input.render().into()
+}
+
+/// Trait declaration annotated with one of the attribute macros. Unlike `syn::ItemTrait`,
+/// the body is kept as tokens, so that `full` feature of `syn` is not required.
+struct TraitDecl {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    unsafety: Option<Token![unsafe]>,
+    auto_token: Option<Token![auto]>,
+    trait_token: Token![trait],
+    ident: Ident,
     generics: Generics,
-    _eq_token: Token![=],
-    traits: TypeTraitObject,
+    colon_token: Option<Token![:]>,
+    supertraits: Punctuated<TypeParamBound, Token![+]>,
+    brace_token: token::Brace,
+    body: TokenStream2,
 }
 
-impl TraitSet {
-    /// Attempts to parse doc-comments from the trait attributes
-    /// and returns the results as a single string.
-    /// If multiple doc-comments were provided (e.g. with `///` and `#[doc]`),
-    /// they will be joined with a newline.
-    fn parse_doc(attrs: &[Attribute]) -> Result<Option<String>> {
-        let mut out = String::new();
-
-        for attr in attrs {
-            // Check whether current attribute is `#[doc = "..."]`.
-            if let Meta::NameValue(MetaNameValue { path, lit, .. }) = attr.parse_meta()? {
-                if let Some(path_ident) = path.get_ident() {
-                    if path_ident == "doc" {
-                        if let Lit::Str(doc_comment) = lit {
-                            out += &doc_comment.value();
-                            // Newlines are not included in the literal value,
-                            // so we have to add them manually.
-                            out.push('\n');
-                        }
-                    }
-                }
-            }
-        }
-
-        Ok(if !out.is_empty() { Some(out) } else { None })
-    }
-
-    /// Renders trait alias into a new trait with bounds set.
-    fn render(self) -> TokenStream2 {
-        // Generic and non-generic implementation have slightly different
-        // syntax, so it's simpler to process them individually rather than
-        // try to generalize implementation.
-        if self.generics.params.is_empty() {
-            self.render_non_generic()
-        } else {
-            self.render_generic()
-        }
+impl TraitDecl {
+    /// Checks whether the tokens start a trait declaration, so that syntax errors
+    /// in traits are not reported as misuse of the attributes.
+    fn is_trait(tokens: TokenStream2) -> bool {
+        let peek_trait = |input: ParseStream| {
+            let is_trait = input.call(Attribute::parse_outer).is_ok()
+                && input.parse::<Visibility>().is_ok()
+                && input.parse::<Option<Token![unsafe]>>().is_ok()
+                && input.parse::<Option<Token![auto]>>().is_ok()
+                && input.peek(Token![trait]);
+            input.parse::<TokenStream2>()?;
+            Ok(is_trait)
+        };
+        peek_trait.parse2(tokens).unwrap_or(false)
     }
 
-    /// Renders the trait alias without generic parameters.
-    fn render_non_generic(self) -> TokenStream2 {
-        let visibility = self.visibility;
-        let alias_name = self.alias_name;
-        let bounds = self.traits.bounds;
-        let doc_comment = self.doc_comment.map(|val| quote! { #[doc = #val] });
-        quote! {
-            #doc_comment
-            #visibility trait #alias_name: #bounds {}
-
-            impl<_INNER> #alias_name for _INNER where _INNER: #bounds {}
-        }
+    /// Checks whether the supertraits end at the current position.
+    fn peek_end(input: ParseStream) -> bool {
+        input.peek(Token![where]) || input.peek(token::Brace)
     }
+}
 
-    /// Renders the trait alias with generic parameters.
-    fn render_generic(self) -> TokenStream2 {
-        let visibility = self.visibility;
-        let alias_name = self.alias_name;
-        let bounds = self.traits.bounds;
-        let doc_comment = self.doc_comment.map(|val| quote! { #[doc = #val] });
-
-        // We differentiate `generics` and `bound_generics` because in the
-        // `impl<X> Trait<Y>` block there must be no trait bounds in the `<Y>` part,
-        // they must go into `<X>` part only.
-        // E.g. `impl<X: Send, _INNER> Trait<X> for _INNER`.
-        let mut unbound_generics = self.generics.clone();
-        for param in unbound_generics.params.iter_mut() {
-            if let GenericParam::Type(ty) = param {
-                if !ty.bounds.is_empty() {
-                    ty.bounds.clear();
+impl Parse for TraitDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let unsafety = input.parse()?;
+        let auto_token = input.parse()?;
+        let trait_token = input.parse()?;
+        let ident = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        let colon_token: Option<Token![:]> = input.parse()?;
+        let mut supertraits = Punctuated::new();
+        if colon_token.is_some() {
+            while !Self::peek_end(input) {
+                supertraits.push_value(input.parse()?);
+                if Self::peek_end(input) {
+                    break;
                 }
+                supertraits.push_punct(input.parse()?);
             }
         }
-        let unbound_generics = unbound_generics.params;
-        let bound_generics = self.generics.params;
-
-        // Note that it's important for `_INNER` to go *after* user-defined
-        // generics, because generics can contain lifetimes, and lifetimes
-        // should always go first.
-        quote! {
-            #doc_comment
-            #visibility trait #alias_name<#bound_generics>: #bounds {}
-
-            impl<#bound_generics, _INNER> #alias_name<#unbound_generics> for _INNER where _INNER: #bounds {}
-        }
+        generics.where_clause = input.parse()?;
+        let content;
+        let brace_token = braced!(content in input);
+        let body = content.parse()?;
+        Ok(Self {
+            attrs,
+            vis,
+            unsafety,
+            auto_token,
+            trait_token,
+            ident,
+            generics,
+            colon_token,
+            supertraits,
+            brace_token,
+            body,
+        })
     }
 }
 
-impl Parse for TraitSet {
-    fn parse(input: ParseStream) -> Result<Self> {
-        let attrs: Vec<Attribute> = input.call(Attribute::parse_outer)?;
-        let result = TraitSet {
-            doc_comment: Self::parse_doc(&attrs)?,
-            visibility: input.parse()?,
-            _trait_token: input.parse()?,
-            alias_name: input.parse()?,
-            generics: input.parse()?,
-            _eq_token: input.parse()?,
-            traits: input.parse()?,
-        };
-
-        if let Some(where_clause) = result.generics.where_clause {
-            return Err(Error::new(
-                where_clause.span(),
-                "Where clause is not allowed for trait alias",
-            ));
+impl ToTokens for TraitDecl {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        for attr in &self.attrs {
+            attr.to_tokens(tokens);
         }
-        Ok(result)
+        self.vis.to_tokens(tokens);
+        self.unsafety.to_tokens(tokens);
+        self.auto_token.to_tokens(tokens);
+        self.trait_token.to_tokens(tokens);
+        self.ident.to_tokens(tokens);
+        self.generics.to_tokens(tokens);
+        self.colon_token.to_tokens(tokens);
+        self.supertraits.to_tokens(tokens);
+        self.generics.where_clause.to_tokens(tokens);
+        self.brace_token
+            .surround(tokens, |tokens| self.body.to_tokens(tokens));
     }
 }
 
-/// Represents a sequence of trait aliases delimited by semicolon.
-struct ManyTraitSet {
-    entries: Punctuated<TraitSet, Token![;]>,
-}
+/// Declares a trait alias from a trait item, which plays nicer with rustfmt than
+/// a macro block. The bounds are taken from the attribute and the supertraits:
+///
+/// ```rust
+/// #[trait_set::alias(Send + Sync)]
+/// pub trait ThreadSafe {}
+///
+/// /// Same as `pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;`
+/// #[trait_set::alias]
+/// pub trait ThreadSafeIterator<T>: ThreadSafe + Iterator<Item = T> {}
+/// ```
+///
+/// The trait is declared the same way as with [`trait_set!`](trait_set), so
+/// attributes such as `#[trait_set(bounds_str = "...")]` are supported, while
+/// the trait body must be empty.
+#[proc_macro_attribute]
+pub fn alias(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut bounds =
+        parse_macro_input!(attr with Punctuated::<TypeParamBound, Token![+]>::parse_terminated);
+    let input = parse_macro_input!(item as TraitDecl);
 
-impl Parse for ManyTraitSet {
-    fn parse(input: ParseStream) -> Result<Self> {
-        Ok(ManyTraitSet {
-            entries: input.parse_terminated(TraitSet::parse)?,
-        })
+    let misuse = if let Some(auto_token) = input.auto_token {
+        Some((auto_token.span, "can't be `auto`"))
+    } else if let Some(token) = input.body.clone().into_iter().next() {
+        Some((token.span(), "must have an empty body"))
+    } else if bounds.is_empty() && input.supertraits.is_empty() {
+        Some((
+            input.ident.span(),
+            "has no bounds, set them in the attribute or as supertraits",
+        ))
+    } else {
+        None
+    };
+    if let Some((span, message)) = misuse {
+        return Error::new(
+            span,
+            format!(
+                "TS0026: Trait declared with `#[trait_set::alias]` {}",
+                message
+            ),
+        )
+        .to_compile_error()
+        .into();
     }
-}
 
-impl ManyTraitSet {
-    fn render(self) -> TokenStream2 {
-        TokenStream2::from_iter(self.entries.into_iter().map(|entry| entry.render()))
-    }
+    bounds.extend(input.supertraits);
+    let attrs = input.attrs;
+    let visibility = input.vis;
+    let unsafety = input.unsafety;
+    let alias_name = input.ident;
+    let where_clause = &input.generics.where_clause;
+    let generics = &input.generics;
+    trait_set(
+        quote! {
+            #(#attrs)*
+            #visibility #unsafety trait #alias_name #generics = #bounds #where_clause;
+        }
+        .into(),
+    )
 }
 
-/// Creates an alias for set of traits.
+/// Checks at compile time that the annotated type satisfies the listed bounds,
+/// e.g. trait aliases.
 ///
-/// To demonstrate the idea, see the examples:
+/// The error is reported at the type definition, so the capability contract
+/// of the type is both documented and enforced where the type is defined:
 ///
 /// ```rust
 /// use trait_set::trait_set;
 ///
 /// trait_set! {
-///     /// Doc-comments are also supported btw.
 ///     pub trait ThreadSafe = Send + Sync;
-///     pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
-///     pub trait ThreadSafeBytesIterator = ThreadSafeIterator<u8>;
-///     pub trait StaticDebug = 'static + std::fmt::Debug;
+///     pub trait Persistable = Clone + std::fmt::Debug;
 /// }
-///```
 ///
-/// This macro also supports [higher-rank trait bound][hrtb]:
+/// #[trait_set::require(ThreadSafe, Persistable)]
+/// #[derive(Clone, Debug)]
+/// struct Config {
+///     name: String,
+/// }
+/// ```
+///
+/// For generic types the check is performed with the bounds declared on the type:
 ///
 /// ```rust
-/// # pub trait Serializer {
-/// #     type Ok;
-/// #     type Error;
-/// #
-/// #     fn ok_value() -> Self::Ok;
-/// # }
-/// # pub trait Deserializer<'de> {
-/// #     type Error;
-/// # }
-/// #
-/// # pub trait Serialize {
-/// #     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-/// #     where
-/// #         S: Serializer;
-/// # }
-/// #
-/// # pub trait Deserialize<'de>: Sized {
-/// #     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-/// #     where
-/// #         D: Deserializer<'de>;
+/// # use trait_set::trait_set;
+/// # trait_set! {
+/// #     pub trait ThreadSafe = Send + Sync;
 /// # }
-/// #
-/// # impl Serializer for u8 {
-/// #     type Ok = ();
-/// #     type Error = ();
-/// #
-/// #     fn ok_value() -> Self::Ok {
-/// #         ()
+/// #[trait_set::require(ThreadSafe)]
+/// struct Wrapper<T: Send + Sync> {
+///     inner: T,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn require(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let bounds =
+        parse_macro_input!(attr with Punctuated::<TypeParamBound, Token![,]>::parse_terminated);
+    let item_tokens = TokenStream2::from(item.clone());
+    let input = parse_macro_input!(item as DeriveInput);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Generic parameters of the type must be passed to the check explicitly,
+    // since nested functions can't use generic parameters of the outer one.
+    // Lifetimes are left to inference: they can't be specified explicitly
+    // if some of them are late-bound.
+    let generic_args: Vec<TokenStream2> = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(_) => None,
+            GenericParam::Type(ty) => Some(ty.ident.to_token_stream()),
+            GenericParam::Const(constant) => Some(constant.ident.to_token_stream()),
+        })
+        .collect();
+    let checks = TokenStream2::from_iter(bounds.iter().map(|bound| {
+        let mut required_generics = input.generics.clone();
+        required_generics
+            .params
+            .push(parse_quote! { _T: ?Sized + #bound });
+        let (required_generics, _, required_where_clause) = required_generics.split_for_impl();
+        // The check is spanned to the type name, so that the error points at it.
+        let check = quote_spanned! { name.span()=>
+            _required::<#(#generic_args,)* #name #ty_generics>();
+        };
+        quote! {
+            {
+                fn _required #required_generics () #required_where_clause {}
+                #check
+            }
+        }
+    }));
+
+    #[cfg(feature = "static-assertions")]
+    if input.generics.params.is_empty() {
+        if let Some(paths) = trait_paths(&bounds) {
+            return quote! {
+                #item_tokens
+
+                ::static_assertions::assert_impl_all!(#name: #(#paths),*);
+            }
+            .into();
+        }
+    }
+
+    quote! {
+        #item_tokens
+
+        // Checks carry all generic parameters of the type, even the ones
+        // they don't need, so they must not trip lifetime lints.
+        #[allow(unused_lifetimes, single_use_lifetimes)]
+        const _: () = {
+            // The argument is never passed, it only brings the implied bounds
+            // of the type (e.g. `T: 'a` for `&'a T` fields) into scope.
+            fn _assert_required #impl_generics (_: &#name #ty_generics) #where_clause {
+                #checks
+            }
+        };
+    }
+    .into()
+}
+
+/// Applies the [sealed trait pattern][sealed] to the annotated trait: it can only be
+/// implemented for the listed types, since its supertrait can't be named outside of
+/// the module declaring the trait.
+///
+/// ```rust
+/// #[trait_set::seal(u8, u16, String)]
+/// pub trait Key {
+///     fn key(&self) -> String;
+/// }
+///
+/// impl Key for u8 {
+///     fn key(&self) -> String {
+///         self.to_string()
+///     }
+/// }
+/// # impl Key for u16 {
+/// #     fn key(&self) -> String {
+/// #         self.to_string()
 /// #     }
 /// # }
-/// #
-/// # impl<'de> Deserializer<'de> for u8 {
-/// #     type Error = ();
-/// # }
-/// #
-/// # impl Serialize for u8 {
-/// #     fn serialize<S>(&self, _serializer: S) -> Result<S::Ok, S::Error>
-/// #     where
-/// #         S: Serializer
-/// #     {
-/// #         Ok(S::ok_value())
+/// # impl Key for String {
+/// #     fn key(&self) -> String {
+/// #         self.clone()
 /// #     }
 /// # }
-/// #
-/// # impl<'de> Deserialize<'de> for u8 {
-/// #     fn deserialize<D>(_deserializer: D) -> Result<Self, D::Error>
-/// #     where
-/// #         D: Deserializer<'de>
-/// #         {
-/// #             Ok(0u8)
-/// #         }
-/// # }
-/// use trait_set::trait_set;
+/// ```
 ///
-/// trait_set!{
-///     pub trait Serde = Serialize + for<'de> Deserialize<'de>;
-///     // Note that you can also use lifetimes as a generic parameter.
-///     pub trait SerdeLifetimeTemplate<'de> = Serialize + Deserialize<'de>;
-/// }
+/// Implementing the trait for other types fails to compile, both in downstream crates
+/// and in the crate declaring the trait:
+///
+/// ```compile_fail
+/// #[trait_set::seal(u8)]
+/// pub trait Key {}
+///
+/// impl Key for u16 {}
 /// ```
 ///
-/// [hrtb]: https://doc.rust-lang.org/nomicon/hrtb.html
-#[proc_macro]
-pub fn trait_set(tokens: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(tokens as ManyTraitSet);
-    input.render().into()
+/// The listed types must be concrete, i.e. have no generic parameters.
+///
+/// [sealed]: https://rust-lang.github.io/api-guidelines/future-proofing.html#sealed-traits-protect-against-downstream-implementations-c-sealed
+#[proc_macro_attribute]
+pub fn seal(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let types = parse_macro_input!(attr with Punctuated::<Type, Token![,]>::parse_terminated);
+    let types = types.iter();
+    let item_tokens = TokenStream2::from(item.clone());
+    let mut input = match syn::parse::<TraitDecl>(item) {
+        Ok(input) => input,
+        Err(error) if TraitDecl::is_trait(item_tokens.clone()) => {
+            return error.to_compile_error().into();
+        }
+        Err(_) => {
+            let span = item_tokens
+                .into_iter()
+                .next()
+                .map_or_else(Span::call_site, |token| token.span());
+            return Error::new(
+                span,
+                "TS0025: `#[trait_set::seal]` can only be applied to traits",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let (module, sealed_module) = render_sealed_module(&input.ident);
+    input.colon_token.get_or_insert_with(Default::default);
+    input.supertraits.push(parse_quote! { #module::Sealed });
+
+    quote! {
+        #input
+
+        #sealed_module
+
+        #(impl #module::Sealed for #types {})*
+    }
+    .into()
 }
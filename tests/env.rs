@@ -0,0 +1,29 @@
+#[test]
+#[cfg(feature = "extras")]
+fn ui_env() {
+    // Test binaries are run in separate processes, so the variables
+    // don't affect other UI tests.
+    std::env::set_var("TRAIT_SET_REQUIRE_DOCS", "true");
+    std::env::set_var("TRAIT_SET_FORBID", "Copy, internal::Unstable");
+    let diagnostics_file =
+        std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("trait_set_diagnostics.jsonl");
+    let _ = std::fs::remove_file(&diagnostics_file);
+    std::env::set_var("TRAIT_SET_DIAGNOSTICS_FILE", &diagnostics_file);
+
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/env/*.rs");
+    // Tests are run when the test cases are dropped.
+    drop(t);
+
+    if cfg!(feature = "diagnostics-file") {
+        let diagnostics = std::fs::read_to_string(&diagnostics_file).unwrap();
+        assert!(diagnostics.lines().any(|line| line.starts_with(
+            r#"{"level":"error","code":"TS0017","message":"TS0017: Public alias `ThreadSafe` must be documented"#
+        )));
+        assert!(diagnostics.lines().any(|line| line.starts_with(
+            r#"{"level":"warning","code":"possible_typo","message":"`TreadSafe` is not declared in this `trait_set!` invocation, did you mean `ThreadSafe`?","suggestion":"ThreadSafe","#
+        ) && line.ends_with(r#""line":14,"column":39}"#)));
+    } else {
+        assert!(!diagnostics_file.exists());
+    }
+}
@@ -0,0 +1,16 @@
+//! UI tests for the output of optional features.
+//! Run with `cargo test --features do-not-recommend` and `cargo test --no-default-features`.
+
+#[test]
+#[cfg(feature = "do-not-recommend")]
+fn ui_do_not_recommend() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/features/do_not_recommend.rs");
+}
+
+#[test]
+#[cfg(not(feature = "extras"))]
+fn ui_no_extras() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/features/no_extras.rs");
+}
@@ -0,0 +1,48 @@
+//! Checks that const trait aliases can be used in `const fn` bounds.
+
+use trait_set::trait_set;
+
+pub const trait Zero {
+    fn zero() -> Self;
+}
+
+pub const trait One {
+    fn one() -> Self;
+}
+
+impl const Zero for u8 {
+    fn zero() -> Self {
+        0
+    }
+}
+
+impl const One for u8 {
+    fn one() -> Self {
+        1
+    }
+}
+
+pub const trait Convert<T> {
+    fn convert(self) -> T;
+}
+
+impl const Convert<u16> for u8 {
+    fn convert(self) -> u16 {
+        self as u16
+    }
+}
+
+trait_set! {
+    pub const trait Number = Zero + One + Copy + 'static;
+    pub const trait ConvertibleNumber<T> = Number + Convert<T>;
+}
+
+const fn one_plus_zero<T: [const] ConvertibleNumber<u16>>() -> u16 {
+    T::one().convert() + T::zero().convert()
+}
+
+#[test]
+fn const_alias() {
+    const VALUE: u16 = one_plus_zero::<u8>();
+    assert_eq!(VALUE, 1);
+}
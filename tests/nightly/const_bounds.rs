@@ -0,0 +1,56 @@
+//! Checks that `~const` and `[const]` bounds make const trait aliases where only
+//! the marked bounds are const.
+
+use trait_set::trait_set;
+
+pub const trait Double {
+    fn double(self) -> Self;
+}
+
+pub const trait Halve {
+    fn halve(self) -> Self;
+}
+
+impl const Double for u8 {
+    fn double(self) -> Self {
+        self * 2
+    }
+}
+
+impl const Halve for u8 {
+    fn halve(self) -> Self {
+        self / 2
+    }
+}
+
+pub trait Describe {
+    fn describe(&self) -> &'static str;
+}
+
+impl Describe for u8 {
+    fn describe(&self) -> &'static str {
+        "byte"
+    }
+}
+
+trait_set! {
+    use self::Halve as Half;
+
+    pub trait ConstOps = ~const Double + [const] Half + Copy;
+    pub trait DescribedOps = ConstOps + ~const Double + Describe;
+}
+
+const fn double_half<T: [const] ConstOps>(value: T) -> T {
+    value.double().halve()
+}
+
+fn describe<T: DescribedOps>(value: T) -> &'static str {
+    value.double().describe()
+}
+
+#[test]
+fn const_bounds() {
+    const VALUE: u8 = double_half(21u8);
+    assert_eq!(VALUE, 21);
+    assert_eq!(describe(1u8), "byte");
+}
@@ -0,0 +1,15 @@
+//! Tests for the features that require nightly compiler.
+//! Run with `cargo +nightly test --features nightly-const,trait-alias`.
+//!
+//! Modules are declared out of line, so that the nightly-only syntax
+//! is not even parsed on stable.
+
+#![cfg_attr(feature = "nightly", feature(const_trait_impl))]
+#![cfg_attr(feature = "trait-alias", feature(trait_alias))]
+
+#[cfg(feature = "nightly")]
+mod const_alias;
+#[cfg(feature = "nightly-const")]
+mod const_bounds;
+#[cfg(feature = "trait-alias")]
+mod native_alias;
@@ -0,0 +1,30 @@
+//! Checks that aliases are rendered as native trait aliases with `trait-alias` feature.
+
+use std::fmt::Debug;
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send)]
+
+    /// Thread-safe types.
+    #[trait_set(bounds_str = "THREAD_SAFE")]
+    pub trait ThreadSafe = Send + Sync;
+    pub trait Items<'a, T: 'a> = Iterator<Item = &'a T> + ThreadSafe where T: Debug;
+    pub trait Values = Iterator<Item = _> + Send;
+}
+
+fn print<'a, T: Debug + 'a, I: Items<'a, T>>(items: I) -> Vec<String> {
+    items.map(|item| format!("{:?}", item)).collect()
+}
+
+fn count<V: Values<u8>>(values: V) -> usize {
+    values.count()
+}
+
+#[test]
+fn native_alias() {
+    assert_eq!(print([1u8, 2].iter()), vec!["1", "2"]);
+    assert_eq!(count(vec![1u8].into_iter()), 1);
+    assert_eq!(THREAD_SAFE, "Send + Sync");
+}
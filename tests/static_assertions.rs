@@ -0,0 +1,36 @@
+//! Checks that requirements are rendered via `static_assertions` macros
+//! with `static-assertions` feature. Run with `cargo test --features static-assertions`.
+
+#![cfg(feature = "static-assertions")]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send + std::fmt::Debug)]
+    #![require(Sync + 'static)]
+
+    pub trait ThreadSafe = Send + Sync + std::fmt::Debug + 'static;
+    pub trait Items<T> = Iterator<Item = T> + Send + Sync + std::fmt::Debug + 'static;
+}
+
+#[trait_set::require(ThreadSafe, Clone)]
+#[derive(Clone, Debug)]
+struct Config {
+    _name: String,
+}
+
+#[trait_set::require(ThreadSafe, 'static)]
+#[derive(Debug)]
+struct Wrapper<T: ThreadSafe> {
+    _inner: T,
+}
+
+#[test]
+fn requirements_compile() {
+    fn thread_safe<T: ThreadSafe>(_: T) {}
+
+    thread_safe(Config {
+        _name: String::new(),
+    });
+    thread_safe(Wrapper { _inner: 1u8 });
+}
@@ -9,3 +9,17 @@ fn ui_fail() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/incorrect/*.rs");
 }
+
+#[test]
+#[cfg(feature = "extras")]
+fn ui_extras_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/extras/correct/*.rs");
+}
+
+#[test]
+#[cfg(feature = "extras")]
+fn ui_extras_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/extras/incorrect/*.rs");
+}
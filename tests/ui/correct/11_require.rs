@@ -0,0 +1,19 @@
+//! Checks that invocation-level requirements are accepted when every
+//! alias implies them.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send + Sync)]
+    #![require('static)]
+
+    pub trait ThreadSafe = Send + Sync + 'static;
+    pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+    pub trait ThreadSafeLifetimeTemplate<'a, T: 'static> = ThreadSafe + AsRef<&'a T>;
+}
+
+fn test_set<T: ThreadSafeIterator<u8>>(_arg: T) {}
+
+fn main() {
+    test_set([10u8, 20, 30].as_ref().iter().copied());
+}
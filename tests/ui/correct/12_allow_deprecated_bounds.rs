@@ -0,0 +1,25 @@
+//! Checks that deprecated traits can be used in alias without warnings
+//! when `allow_deprecated_bounds` option is set.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+#[deprecated(note = "Use `NewTrait` instead")]
+pub trait OldTrait {}
+
+#[allow(deprecated)]
+impl OldTrait for u8 {}
+
+trait_set! {
+    #[trait_set(allow_deprecated_bounds)]
+    pub trait Migrating = OldTrait + Send;
+    #[trait_set(allow_deprecated_bounds)]
+    pub trait GenericMigrating<T> = OldTrait + Iterator<Item = T>;
+}
+
+fn test_set<T: Migrating>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
@@ -0,0 +1,17 @@
+//! Checks that generic parameters of an alias can be declared in any order.
+//! Lifetimes are moved to the front, so they go first at the use site.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub(crate) trait RefIterator<T: 'a, 'a> = Iterator<Item = &'a T>;
+    pub(crate) trait RefPairIterator<A: 'a, 'a, B: 'b, 'b> = Iterator<Item = (&'a A, &'b B)>;
+}
+
+fn test_set<'a, T: RefIterator<'a, u8>>(_arg: T) {}
+fn test_pair<'a, 'b, T: RefPairIterator<'a, 'b, u8, u16>>(_arg: T) {}
+
+fn main() {
+    test_set([10u8, 20, 30].iter());
+    test_pair([10u8, 20, 30].iter().zip([10u16, 20, 30].iter()));
+}
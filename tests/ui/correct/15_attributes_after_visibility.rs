@@ -0,0 +1,17 @@
+//! Checks that attributes can be placed after the visibility modifier.
+
+use trait_set::trait_set;
+
+trait_set! {
+    /// Doc-comment before visibility.
+    pub(crate)
+    /// Doc-comment after visibility.
+    #[trait_set(allow_deprecated_bounds)]
+    trait TraitSet = Send + Sync;
+}
+
+fn test_set<T: TraitSet>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
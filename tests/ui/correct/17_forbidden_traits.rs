@@ -0,0 +1,21 @@
+//! Checks that aliases not using forbidden traits are accepted.
+
+use trait_set::trait_set;
+
+mod internal {
+    pub trait Unstable {}
+}
+
+trait_set! {
+    #![trait_set(forbid(Copy, internal::Unstable))]
+
+    pub trait Cloneable = Clone + Send;
+    // Traits with the same name from the other modules are allowed.
+    pub trait Unstable = std::fmt::Debug;
+}
+
+fn test_set<T: Cloneable + Unstable>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
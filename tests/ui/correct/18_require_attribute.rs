@@ -0,0 +1,40 @@
+//! Checks that `#[trait_set::require]` accepts types satisfying the aliases.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+    pub trait Persistable = Clone + std::fmt::Debug;
+    pub trait GenericIterator<T> = Iterator<Item = T>;
+}
+
+#[trait_set::require(ThreadSafe, Persistable)]
+#[derive(Clone, Debug)]
+struct Config {
+    _name: String,
+}
+
+#[trait_set::require(ThreadSafe, Persistable, 'static)]
+#[derive(Clone, Debug)]
+enum Mode {
+    _Fast,
+    _Slow,
+}
+
+#[trait_set::require(ThreadSafe, GenericIterator<&'a T>)]
+struct Refs<'a, T: Sync>
+where
+    T: 'a,
+{
+    _inner: std::slice::Iter<'a, T>,
+}
+
+impl<'a, T: Sync> Iterator for Refs<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self._inner.next()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+//! Checks that lint attributes of an alias apply to the generated items.
+
+#![deny(non_camel_case_types)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[allow(non_camel_case_types)]
+    pub trait thread_safe = Send + Sync;
+    #[allow(non_camel_case_types)]
+    pub trait generic_iterator<T> = Iterator<Item = T>;
+}
+
+fn test_set<T: thread_safe>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
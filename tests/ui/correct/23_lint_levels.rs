@@ -0,0 +1,20 @@
+//! Checks that lints of the macro can be allowed inside the invocation.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![allow(trait_set::possible_typo, trait_set::private_component)]
+
+    trait ThreadSafe = Send + Sync;
+    pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+    pub trait Threadsafe = Send;
+    pub trait Sendable = Threadsafe;
+}
+
+fn test_set<T: ThreadSafeIterator<u8> + Sendable>(_arg: T) {}
+
+fn main() {
+    test_set(std::iter::empty::<u8>());
+}
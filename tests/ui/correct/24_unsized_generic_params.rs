@@ -0,0 +1,30 @@
+//! Checks that bounds of alias generic parameters, including `?Sized`,
+//! lifetime bounds and `const` parameters, are preserved.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Sized)]
+
+    pub trait Formatter<T: ?Sized> = Fn(&T) -> String;
+    pub trait Borrowed<'a, 'b: 'a, T: ?Sized + 'b> = Fn(&'a T) -> &'b T;
+    pub trait Bytes<const N: usize> = std::borrow::Borrow<[u8; N]>;
+}
+
+fn format<F: Formatter<str>>(formatter: F) -> String {
+    formatter("value")
+}
+
+fn borrow<'a, 'b: 'a, F: Borrowed<'a, 'b, [u8]>>(f: F, value: &'b [u8]) -> &'b [u8] {
+    f(value)
+}
+
+fn len<B: Bytes<4>>(bytes: B) -> usize {
+    bytes.borrow().len()
+}
+
+fn main() {
+    assert_eq!(format(|value: &str| value.to_uppercase()), "VALUE");
+    assert_eq!(borrow(|value| value, &[1, 2]), &[1, 2]);
+    assert_eq!(len([0u8; 4]), 4);
+}
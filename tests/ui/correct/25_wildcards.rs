@@ -0,0 +1,28 @@
+//! Checks that `_` in the bounds becomes a new generic parameter of the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait AnyIterator = Iterator<Item = _> + Send;
+    pub trait Mapper<'a, T: 'a> = Fn(&'a T, _) -> _;
+    // Same as `pub trait Stream<__I0, T = u8> = Iterator<Item = T> + AsRef<[__I0]>;`
+    pub trait Stream<T = u8> = Iterator<Item = T> + AsRef<[_]>;
+}
+
+fn count<I: AnyIterator<T>, T>(iter: I) -> usize {
+    iter.count()
+}
+
+fn map<'a, M: Mapper<'a, u8, u8, String>>(mapper: M) -> String {
+    mapper(&1, 2)
+}
+
+fn remaining<S: Stream<u8>>(stream: S) -> usize {
+    stream.as_ref().len()
+}
+
+fn main() {
+    assert_eq!(count(vec![1u8, 2].into_iter()), 2);
+    assert_eq!(map(|a: &u8, b: u8| (a + b).to_string()), "3");
+    assert_eq!(remaining(vec![1u8, 2].into_iter()), 2);
+}
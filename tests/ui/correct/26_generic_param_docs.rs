@@ -0,0 +1,26 @@
+//! Checks that doc-comments of generic parameters are accepted.
+
+#![deny(missing_docs, unused_doc_comments)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    /// Iterator over borrowed entries.
+    pub trait Entries<
+        /// Lifetime of the entries.
+        'a,
+        /// The key type.
+        K: 'a,
+        /// The value type,
+        /// spanning two lines.
+        V,
+    > = Iterator<Item = (&'a K, V)>;
+}
+
+fn count<'a, I: Entries<'a, u8, u16>>(entries: I) -> usize {
+    entries.count()
+}
+
+fn main() {
+    assert_eq!(count(vec![(&1, 2)].into_iter()), 1);
+}
@@ -0,0 +1,20 @@
+//! Checks that the docs with the equivalent nightly trait alias can be generated.
+
+#![deny(missing_docs)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(nightly_equivalent)]
+
+    /// Storage of borrowed values.
+    pub trait Storage<'a, K: 'a + Clone, const N: usize> = Iterator<Item = [&'a K; N]>;
+    /// Types that can be shared between threads.
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn test_set<T: ThreadSafe>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
@@ -0,0 +1,28 @@
+//! Checks that generic parameters of an alias can have defaults, including `Self`.
+
+use std::ops::{Add, Sub};
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Ops<Rhs = Self> = Add<Rhs, Output = Self> + Sub<Rhs, Output = Self>;
+    pub trait Bytes<'a, T: 'a = u8, const N: usize = 4> = std::borrow::Borrow<[T; N]> + 'a;
+}
+
+fn add_sub<T: Ops + Copy>(a: T, b: T) -> T {
+    a + b - b
+}
+
+fn scale<T: Ops<u8>>(a: T) -> T {
+    a + 2 - 1
+}
+
+fn first<'a, B: Bytes<'a>>(bytes: &B) -> u8 {
+    bytes.borrow()[0]
+}
+
+fn main() {
+    assert_eq!(add_sub(1, 2), 1);
+    assert_eq!(scale(1u8), 2);
+    assert_eq!(first(&[1u8, 2, 3, 4]), 1);
+}
@@ -0,0 +1,48 @@
+//! Checks that inline aliases are flattened into other aliases of the invocation.
+
+#![deny(missing_docs)]
+//! Crate docs.
+
+use trait_set::trait_set;
+
+mod aliases {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(require_docs)]
+
+        #[trait_set(inline)]
+        trait ThreadSafe = Send + Sync;
+        #[trait_set(inline)]
+        pub trait Printable = std::fmt::Debug + std::fmt::Display;
+        #[trait_set(inline)]
+        trait Shared = ThreadSafe + 'static;
+
+        /// Values that can be shared between threads and printed.
+        pub trait SharedValue = Shared + Printable + Clone;
+    }
+}
+
+trait_set! {
+    #[trait_set(inline)]
+    trait Number = Copy + std::ops::Add<Output = Self>;
+
+    /// Numbers that can be sent to other threads.
+    pub trait SendNumber = Number + Send;
+}
+
+/// Trait with the same name as an inline alias, which must not be shadowed.
+pub trait ThreadSafe {}
+
+fn print<T: aliases::SharedValue>(value: T) -> String {
+    format!("{} {:?}", value.clone(), value)
+}
+
+fn double<T: SendNumber>(value: T) -> T {
+    value + value
+}
+
+fn main() {
+    assert_eq!(print("a"), "a \"a\"");
+    assert_eq!(double(2), 4);
+}
@@ -0,0 +1,26 @@
+//! Checks that `@auto` shorthand expands to the well-known auto traits,
+//! and that the set can be narrowed with `auto_traits` option.
+
+use std::cell::Cell;
+
+trait_set::trait_set! {
+    pub trait Service = Clone + @auto + 'static;
+    pub trait Auto = @auto;
+}
+
+trait_set::trait_set! {
+    #![trait_set(auto_traits(Unpin, UnwindSafe))]
+
+    pub trait Local = @auto + Clone;
+}
+
+fn service<T: Service>(_: T) {}
+fn auto<T: Auto>(_: T) {}
+fn local<T: Local>(_: T) {}
+
+fn main() {
+    service(1u8);
+    auto(String::new());
+    // Neither `Sync` nor `RefUnwindSafe`.
+    local(Cell::new(1u8));
+}
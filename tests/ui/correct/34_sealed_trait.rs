@@ -0,0 +1,43 @@
+//! Checks that `#[trait_set::seal]` keeps the trait usable for the listed types.
+
+mod keys {
+    #[trait_set::seal(u8, String, Vec<u8>)]
+    pub trait Key: Clone {
+        fn key(&self) -> String;
+    }
+
+    impl Key for u8 {
+        fn key(&self) -> String {
+            self.to_string()
+        }
+    }
+
+    impl Key for String {
+        fn key(&self) -> String {
+            self.clone()
+        }
+    }
+
+    impl Key for Vec<u8> {
+        fn key(&self) -> String {
+            format!("{:?}", self)
+        }
+    }
+
+    #[trait_set::seal()]
+    pub trait Unimplementable<T>
+    where
+        T: Key,
+    {
+    }
+}
+
+fn key<K: keys::Key>(key: K) -> String {
+    key.key()
+}
+
+fn main() {
+    assert_eq!(key(1u8), "1");
+    assert_eq!(key(String::from("a")), "a");
+    assert_eq!(key(vec![1u8]), "[1]");
+}
@@ -0,0 +1,35 @@
+//! Checks that where clauses of aliases are supported both before `=`
+//! and after the bounds, and are carried into the blanket impl.
+
+use std::fmt::Debug;
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Iterator)]
+    #![trait_set(nightly_equivalent)]
+
+    pub trait Items<T> where T: Clone + Send = Iterator<Item = T>;
+    pub trait Pairs<K, V> = Iterator<Item = (K, V)> where K: Ord, V: Debug,;
+    pub trait Printable = Iterator where Self: Clone;
+}
+
+fn first<T: Clone + Send, I: Items<T>>(mut items: I) -> Option<T> {
+    items.next()
+}
+fn keys<K: Ord, V: Debug, P: Pairs<K, V>>(pairs: P) -> Vec<K> {
+    pairs.map(|(key, _)| key).collect()
+}
+// Like for regular traits, only the predicates on `Self` are implied.
+fn print<P: Printable>(items: P) -> Vec<String>
+where
+    P::Item: Debug,
+{
+    items.clone().map(|item| format!("{:?}", item)).collect()
+}
+
+fn main() {
+    assert_eq!(first(vec![1u8, 2].into_iter()), Some(1));
+    assert_eq!(keys(vec![(1, "a"), (2, "b")].into_iter()), vec![1, 2]);
+    assert_eq!(print(vec![1, 2].into_iter()), vec!["1", "2"]);
+}
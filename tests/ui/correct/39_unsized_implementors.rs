@@ -0,0 +1,30 @@
+//! Checks that unsized types implement aliases, and that `?Sized`
+//! is accepted in alias bounds.
+
+use std::fmt::Debug;
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait AnyDebug = Debug + ?Sized;
+    pub trait Printable = Debug;
+    pub trait Items<T> = AsRef<[T]> + ?Sized + Send;
+}
+
+fn debug<T: AnyDebug + ?Sized>(value: &T) -> String {
+    format!("{:?}", value)
+}
+fn print<T: Printable + ?Sized>(value: &T) -> String {
+    format!("{:?}", value)
+}
+fn len<T, I: Items<T> + ?Sized>(items: &I) -> usize {
+    items.as_ref().len()
+}
+
+fn main() {
+    assert_eq!(debug("str"), "\"str\"");
+    assert_eq!(print(&[1u8, 2][..]), "[1, 2]");
+    let object: &dyn Debug = &1u8;
+    assert_eq!(print(object), "1");
+    assert_eq!(len::<u8, [u8]>(&[1u8, 2][..]), 2);
+}
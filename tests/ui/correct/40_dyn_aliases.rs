@@ -0,0 +1,30 @@
+//! Checks that `dyn_alias` and `box_alias` options generate type aliases
+//! for the trait objects of the alias.
+
+#![deny(missing_docs)]
+//! Crate documentation.
+
+use trait_set::trait_set;
+
+trait_set! {
+    /// Thread-safe iterator.
+    #[trait_set(dyn_alias = "DynThreadSafeIterator", box_alias = "BoxThreadSafeIterator")]
+    pub trait ThreadSafeIterator<T> = Iterator<Item = T> + Send + Sync;
+
+    /// Callback borrowing data for `'a`.
+    #[trait_set(box_alias = "BoxCallback", helpers_vis = "pub(crate)")]
+    pub trait Callback<'a, T: 'a> = Fn(&'a T) -> usize + Send;
+}
+
+fn sum(iter: &mut DynThreadSafeIterator<'_, u8>) -> u8 {
+    iter.sum()
+}
+
+fn main() {
+    let mut boxed: BoxThreadSafeIterator<'static, u8> = Box::new(vec![1u8, 2].into_iter());
+    assert_eq!(sum(&mut *boxed), 3);
+
+    let value = String::from("abc");
+    let callback: BoxCallback<'static, '_, String> = Box::new(|value: &String| value.len());
+    assert_eq!(callback(&value), 3);
+}
@@ -0,0 +1,33 @@
+//! Checks that sealed aliases are implemented only for the types listed in `impl_Alias!`,
+//! and that sealed aliases of several invocations can be used in the same module.
+
+use trait_set::trait_set;
+
+trait_set! {
+    /// Keys of the storage.
+    #[trait_set(sealed)]
+    pub trait Key = Clone + std::fmt::Display + Send;
+    pub trait Value = Clone;
+}
+
+trait_set! {
+    #[trait_set(sealed = "impl_message")]
+    pub(crate) trait Message = Key + 'static where Self: std::fmt::Debug;
+}
+
+impl_Key!(u8, u16, String,);
+impl_message!(String);
+
+fn key<K: Key>(key: K) -> String {
+    key.to_string()
+}
+
+fn message<M: Message>(message: M) -> String {
+    format!("{:?}", message)
+}
+
+fn main() {
+    assert_eq!(key(1u8), "1");
+    assert_eq!(key(String::from("key")), "key");
+    assert_eq!(message(String::from("hi")), "\"hi\"");
+}
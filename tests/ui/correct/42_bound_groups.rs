@@ -0,0 +1,30 @@
+//! Checks that bound groups declared with `set` are written out in place in other aliases
+//! and don't generate any items.
+
+use trait_set::trait_set;
+
+trait_set! {
+    set Common = Send + Sync + 'static;
+    /// Bound groups can refer to each other.
+    set Shared = Common + Clone;
+
+    pub trait Worker = Common + FnMut();
+    pub trait Value = Shared + std::fmt::Debug;
+}
+
+// Bound groups don't clash with items of the same name.
+pub struct Common;
+
+fn spawn<W: Worker>(mut worker: W) {
+    worker();
+}
+
+fn share<V: Value>(value: V) -> V {
+    value.clone()
+}
+
+fn main() {
+    spawn(|| {});
+    assert_eq!(share(1u8), 1);
+    let _ = Common;
+}
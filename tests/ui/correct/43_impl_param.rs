@@ -0,0 +1,31 @@
+//! Checks that the implementor parameter of the blanket impl doesn't clash with
+//! the generic parameters of the alias, and can be set via `impl_param` option.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send)]
+
+    pub trait Items<_INNER> = Iterator<Item = _INNER> + Send;
+    pub trait Nested<_INNER, _INNER_> = Items<(_INNER, _INNER_)> where Self: Clone;
+    #[trait_set(impl_param = "__T")]
+    pub trait Named<T> = Items<T> + ExactSizeIterator where Self: Clone;
+}
+
+fn count<I: Items<u8>>(items: I) -> usize {
+    items.count()
+}
+
+fn pairs<I: Nested<u8, char>>(items: I) -> usize {
+    items.clone().count()
+}
+
+fn len<I: Named<u8>>(items: I) -> usize {
+    items.len()
+}
+
+fn main() {
+    assert_eq!(count(vec![1u8, 2].into_iter()), 2);
+    assert_eq!(pairs(vec![(1u8, 'a')].into_iter()), 1);
+    assert_eq!(len(vec![1u8].into_iter()), 1);
+}
@@ -0,0 +1,25 @@
+//! Checks that the bounds appended to the alias docs don't break the alias,
+//! and that they can be omitted via `no_bounds_doc` option.
+
+#![deny(missing_docs)]
+//! Crate docs.
+
+use trait_set::trait_set;
+
+trait_set! {
+    /// Thread-safe iterators.
+    pub trait Items<T> = Iterator<Item = T> + Send;
+    /// Thread-safe values, the bounds are listed manually: `Send + Sync`.
+    #[trait_set(no_bounds_doc)]
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn count<I: Items<u8>>(items: I) -> usize {
+    items.count()
+}
+
+fn main() {
+    assert_eq!(count(vec![1u8].into_iter()), 1);
+    fn check<T: ThreadSafe>(_: T) {}
+    check(1u8);
+}
@@ -0,0 +1,34 @@
+//! Checks that `#[doc ...]` attributes of an alias are re-emitted as is, including
+//! `doc(hidden)`, `doc(alias)` and doc-comments computed by macros.
+
+#![deny(missing_docs)]
+//! Crate docs.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(require_docs, missing_docs = "generate")]
+
+    /// Thread-safe values.
+    ///
+    #[doc = "```"]
+    /// fn check<T: Send + Sync>() {}
+    #[doc = "```"]
+    #[doc(alias = "Concurrent")]
+    pub trait ThreadSafe = Send + Sync;
+    #[doc = concat!("Iterators over ", "bytes.")]
+    pub trait Bytes = Iterator<Item = u8>;
+    #[doc(hidden)]
+    pub(crate) trait Hidden = Clone;
+}
+
+fn check<T: ThreadSafe + Hidden>(_: T) {}
+
+fn count<I: Bytes>(iter: I) -> usize {
+    iter.count()
+}
+
+fn main() {
+    check(1u8);
+    assert_eq!(count(vec![1u8].into_iter()), 1);
+}
@@ -0,0 +1,51 @@
+//! Checks that aliases of unsafe traits can be declared as `unsafe trait`,
+//! with an `unsafe impl` blanket impl.
+
+use trait_set::trait_set;
+
+/// # Safety
+///
+/// Implementors must consist of plain bytes.
+pub unsafe trait Pod: Copy {}
+/// # Safety
+///
+/// All-zero bytes must be a valid value of implementors.
+pub unsafe trait Zeroable {}
+
+unsafe impl Pod for u32 {}
+unsafe impl Zeroable for u32 {}
+
+trait_set! {
+    /// Plain types that can be zeroed.
+    pub unsafe trait PodSafe = Pod + Zeroable;
+    pub unsafe trait PodArray<const N: usize> = PodSafe + AsRef<[u32; N]>;
+    #[trait_set(sealed)]
+    pub unsafe trait SealedPod = Pod + Zeroable;
+}
+
+impl_SealedPod!(unsafe u32);
+
+/// # Safety
+///
+/// Implementors must be plain bytes that can be zeroed.
+#[trait_set::alias(Pod + Zeroable)]
+pub unsafe trait Plain {}
+
+fn zeroed<T: PodSafe + SealedPod + Plain>() -> T {
+    unsafe { std::mem::zeroed() }
+}
+
+fn main() {
+    assert_eq!(zeroed::<u32>(), 0);
+    fn check<A: PodArray<1>>(_: A) {}
+    #[derive(Clone, Copy)]
+    struct Wrapper([u32; 1]);
+    impl AsRef<[u32; 1]> for Wrapper {
+        fn as_ref(&self) -> &[u32; 1] {
+            &self.0
+        }
+    }
+    unsafe impl Pod for Wrapper {}
+    unsafe impl Zeroable for Wrapper {}
+    check(Wrapper([0]));
+}
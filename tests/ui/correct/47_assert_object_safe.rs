@@ -0,0 +1,18 @@
+//! Checks that `assert_object_safe` option accepts aliases usable as trait objects.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(assert_object_safe)]
+    pub trait Handler = Fn(u8) -> u8 + Send + Sync;
+    #[trait_set(assert_object_safe)]
+    pub trait Items<'a, T: 'a> = Iterator<Item = &'a T> + 'a where T: Ord, Self::Item: Copy;
+}
+
+fn main() {
+    let handler: Box<dyn Handler> = Box::new(|x| x + 1);
+    assert_eq!(handler(1), 2);
+    let values = [1u8, 2];
+    let items: Box<dyn Items<u8>> = Box::new(values.iter());
+    assert_eq!(items.count(), 2);
+}
@@ -0,0 +1,19 @@
+//! Checks that `assert_impl_Alias!` macros generated by `assertions` option
+//! accept types implementing the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(assertions)]
+    pub trait ThreadSafe = Send + Sync;
+    #[trait_set(assertions)]
+    pub trait Items<'a, T: 'a> = Iterator<Item = &'a T>;
+}
+
+assert_impl_ThreadSafe!(u8, String, str,);
+assert_impl_Items!(std::slice::Iter<'static, u8>; 'static, u8);
+
+fn main() {
+    // The macro can be used in function bodies as well, e.g. in tests.
+    assert_impl_ThreadSafe!(Vec<u8>);
+}
@@ -0,0 +1,37 @@
+//! Checks that `hrtb_alias` option generates an alias of the alias for any lifetimes.
+
+use trait_set::trait_set;
+
+pub trait Parser<I> {
+    fn parse(&self, input: I) -> usize;
+}
+
+impl<I: AsRef<str>> Parser<I> for () {
+    fn parse(&self, input: I) -> usize {
+        input.as_ref().len()
+    }
+}
+
+trait_set! {
+    #![require(Send)]
+
+    /// Parsers borrowing the input.
+    #[trait_set(hrtb_alias = "AnyLifetimeParser")]
+    pub trait BorrowingParser<'a> = Parser<&'a str> + Send;
+    #[trait_set(hrtb_alias = "AnyLifetimeConverter")]
+    pub(crate) trait Converter<'a, 'b, T> = Fn(&'a str, &'b str, T) -> T + Send where T: Clone;
+}
+
+fn parse_all<P: AnyLifetimeParser>(parser: P, inputs: &[String]) -> usize {
+    inputs.iter().map(|input| parser.parse(input.as_str())).sum()
+}
+
+fn convert<C: AnyLifetimeConverter<u8>>(converter: C) -> u8 {
+    let (first, second) = (String::from("a"), String::from("bc"));
+    converter(&first, &second, 1)
+}
+
+fn main() {
+    assert_eq!(parse_all((), &[String::from("ab"), String::from("c")]), 3);
+    assert_eq!(convert(|a: &str, b: &str, value| (a.len() + b.len()) as u8 + value), 4);
+}
@@ -0,0 +1,30 @@
+//! Checks that generic parameters of an alias can have full bounds, including
+//! associated type equalities, and can be referred to from the bounds of the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait FromBytes<I: IntoIterator<Item = u8>> = std::iter::FromIterator<u8> + Extend<I::Item>;
+    pub trait SameItem<A> = Iterator<Item = A::Item> where A: Iterator;
+    pub trait Converter<I: IntoIterator<Item = u8> + Clone, O: Default> = Fn(I) -> O;
+}
+
+fn collect<C: FromBytes<Vec<u8>>>(bytes: Vec<u8>) -> C {
+    let mut collection: C = std::iter::empty().collect();
+    collection.extend(bytes);
+    collection
+}
+
+fn zip_sum<A: Iterator<Item = u8>, B: SameItem<A>>(a: A, b: B) -> u8 {
+    a.zip(b).map(|(x, y)| x + y).sum()
+}
+
+fn convert<C: Converter<Vec<u8>, usize>>(converter: C) -> usize {
+    converter(vec![1, 2])
+}
+
+fn main() {
+    assert_eq!(collect::<Vec<u8>>(vec![1, 2]), vec![1, 2]);
+    assert_eq!(zip_sum(vec![1u8].into_iter(), vec![2u8].into_iter()), 3);
+    assert_eq!(convert(|bytes: Vec<u8>| bytes.len()), 2);
+}
@@ -0,0 +1,25 @@
+//! Checks that an alias can have the same name as its bound referred to by
+//! a qualified path, and that `shadowed_bound` lint is allowed by default.
+
+#![deny(warnings)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Error = std::error::Error + Send + Sync + 'static;
+    pub trait Iterator<T> = ::std::iter::Iterator<Item = T> + Send;
+}
+
+fn boxed<E: Error>(error: E) -> Box<dyn std::error::Error + Send + Sync> {
+    Box::new(error)
+}
+
+fn first<I: Iterator<u8>>(mut iter: I) -> Option<u8> {
+    iter.next()
+}
+
+fn main() {
+    let error = std::fmt::Error;
+    assert_eq!(boxed(error).to_string(), "an error occurred when formatting an argument");
+    assert_eq!(first(vec![1u8].into_iter()), Some(1));
+}
@@ -0,0 +1,18 @@
+//! Checks that well-known traits are not reported as misspelled names of aliases
+//! with similar names.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Syncs = Sync + Send;
+    pub trait Sends = Send;
+    pub trait Clones = Clone + Syncs;
+}
+
+fn share<T: Syncs + Sends + Clones>(_: T) {}
+
+fn main() {
+    share(1u8);
+}
@@ -0,0 +1,17 @@
+//! Checks that `@auto` shorthand doesn't refer to `std`, so it works in `no_std` crates.
+
+#![no_std]
+
+// The test is still run as a binary, so the runtime is linked under another name
+// and `::std` can't be resolved.
+extern crate std as runtime;
+
+trait_set::trait_set! {
+    pub trait Service = Clone + @auto + 'static;
+}
+
+fn service<T: Service>(_: T) {}
+
+fn main() {
+    service(1u8);
+}
@@ -0,0 +1,27 @@
+//! Checks that the invocations supported before the lints of the macro were added
+//! still compile under `#![deny(warnings)]`.
+
+#![deny(warnings)]
+
+#[path = "01_simple.rs"]
+mod simple;
+#[path = "02_associated_types.rs"]
+mod associated_types;
+#[path = "03_generic.rs"]
+mod generic;
+#[path = "04_lifetimes.rs"]
+mod lifetimes;
+#[path = "05_multiple.rs"]
+mod multiple;
+#[path = "06_combination.rs"]
+mod combination;
+#[path = "07_serde_hrtb.rs"]
+mod serde_hrtb;
+#[path = "08_interoperability.rs"]
+mod interoperability;
+#[path = "09_generic_param_trait_bounds.rs"]
+mod generic_param_trait_bounds;
+#[path = "10_doc_comment.rs"]
+mod doc_comment;
+
+fn main() {}
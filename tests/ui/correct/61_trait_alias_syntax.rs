@@ -0,0 +1,34 @@
+//! Checks that declarations written for the `trait_alias!` macro crate, i.e.
+//! `trait Name = Bound1 + Bound2;` with an optional visibility prefix, are accepted
+//! as is.
+
+mod aliases {
+    use trait_set::trait_set;
+
+    trait_set! {
+        trait Private = Send + Sync;
+        pub trait Public = Clone + Send + Sync + 'static;
+        pub(crate) trait Crate = Private + Clone;
+        pub(super) trait Super = Copy + Private;
+        pub trait Generic<T> = Iterator<Item = T> + Send;
+        pub trait Bounded<T> = AsRef<[T]> where T: Clone;
+    }
+}
+
+use aliases::{Bounded, Crate, Generic, Public, Super};
+
+fn check<T: Public + Crate + Super>(_: T) {}
+
+fn count<I: Generic<u8>>(iter: I) -> usize {
+    iter.count()
+}
+
+fn first<T: Clone, S: Bounded<T>>(items: S) -> Option<T> {
+    items.as_ref().first().cloned()
+}
+
+fn main() {
+    check(1u8);
+    let _ = count(vec![1u8, 2].into_iter());
+    let _ = first(vec![1u8]);
+}
@@ -0,0 +1,9 @@
+//! Checks that `TRAIT_SET_REQUIRE_DOCS` environment variable enables `require_docs`.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//! Checks that `TRAIT_SET_FORBID` environment variable extends `forbid` option.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(forbid(Clone))]
+
+    /// Documented alias.
+    pub trait Copyable = std::marker::Copy;
+}
+
+fn main() {}
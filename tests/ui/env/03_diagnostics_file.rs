@@ -0,0 +1,17 @@
+//! Checks that `TRAIT_SET_DIAGNOSTICS_FILE` environment variable doesn't affect
+//! the compiler output. Contents of the file are checked by the test itself.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![warn(trait_set::possible_typo)]
+
+    /// Documented alias.
+    pub trait ThreadSafe = Send + Sync;
+    /// Documented alias with a typo.
+    pub trait ThreadSafeIterator<T> = TreadSafe + Iterator<Item = T>;
+}
+
+fn main() {}
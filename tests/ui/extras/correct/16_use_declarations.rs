@@ -0,0 +1,53 @@
+//! Checks that `use` declarations inside the invocation affect only
+//! the bounds of the aliases.
+
+use trait_set::trait_set;
+
+mod inner {
+    pub trait Foo {}
+    pub trait Bar<T> {}
+    pub type Byte = u8;
+
+    impl Foo for u8 {}
+    impl<T> Bar<T> for u8 {}
+
+    pub mod deep {
+        pub trait Baz {}
+
+        impl Baz for u8 {}
+    }
+}
+
+trait_set! {
+    use std::fmt::{self, Debug as Dbg};
+    use inner::{deep, Bar, Byte, Foo};
+
+    pub trait Complex = Foo + Bar<Byte> + deep::Baz + Dbg + fmt::Display;
+    pub trait BytesIterator = Iterator<Item = Byte>;
+    pub trait Generic<T: Foo> = Bar<T>;
+}
+
+trait_set! {
+    // Imports also apply to the invocation-level requirements.
+    #![require(Dbg)]
+
+    use std::fmt::Debug as Dbg;
+
+    pub trait Printable = Dbg + Send;
+}
+
+// Names imported inside the invocation don't leak into the module.
+#[allow(dead_code)]
+trait Foo {}
+#[allow(dead_code)]
+type Byte = u16;
+
+fn test_set<T: Complex>(_arg: T) {}
+fn test_iter<T: BytesIterator>(_arg: T) {}
+fn test_generic<T: Generic<u8>>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+    test_iter([10u8, 20, 30].iter().copied());
+    test_generic(10u8);
+}
@@ -0,0 +1,15 @@
+//! Checks that a string constant with the alias bounds can be generated.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS")]
+    pub trait ThreadSafe = Send + Sync;
+    #[trait_set(bounds_str = "GENERIC_BOUNDS")]
+    pub(crate) trait GenericIterator<'a, T: 'a> = 'a + Iterator<Item = &'a T> + std::fmt::Debug;
+}
+
+fn main() {
+    assert_eq!(THREAD_SAFE_BOUNDS, "Send + Sync");
+    assert_eq!(GENERIC_BOUNDS, "'a + Iterator<Item = &'a T> + std::fmt::Debug");
+}
@@ -0,0 +1,50 @@
+//! Checks that the generated code is clean under strict lint regimes.
+
+#![deny(
+    warnings,
+    rust_2018_idioms,
+    unused_qualifications,
+    unused_lifetimes,
+    single_use_lifetimes,
+    missing_docs,
+    unreachable_pub
+)]
+
+use trait_set::trait_set;
+
+/// Trait with a lifetime parameter.
+pub trait Deserialize<'de> {}
+
+trait_set! {
+    #![require(Send)]
+    #![trait_set(missing_docs = "generate")]
+
+    use std::fmt::Debug;
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait SendIterator<T> = Send + Iterator<Item = T>;
+    pub trait RefIterator<'a, T: 'a> = Send + Iterator<Item = &'a T>;
+    pub trait Borrowed<'de> = Send + Deserialize<'de>;
+    pub trait Owned = Send + for<'de> Deserialize<'de>;
+    #[trait_set(bounds_str = "PRINTABLE")]
+    pub trait Printable = Send + Debug + std::fmt::Display;
+}
+
+/// Iterator over borrowed values.
+#[trait_set::require(ThreadSafe, SendIterator<u8>)]
+#[derive(Debug)]
+pub struct Bytes<'a, T: Sync> {
+    _inner: &'a T,
+}
+
+impl<T: Sync> Iterator for Bytes<'_, T> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        None
+    }
+}
+
+fn main() {
+    let _ = PRINTABLE;
+}
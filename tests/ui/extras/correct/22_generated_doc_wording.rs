@@ -0,0 +1,39 @@
+//! Checks that the wording of generated doc-comments can be overridden.
+
+#![deny(missing_docs)]
+
+/// Aliases with localized doc-comments.
+pub mod localized {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(
+            missing_docs = "generate",
+            generated_doc = "Alias für `{bounds}`.",
+            bounds_str_doc = "Schranken von [`{alias}`] als Zeichenkette."
+        )]
+
+        #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS")]
+        pub trait ThreadSafe = Send + Sync;
+        pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+    }
+}
+
+/// Aliases with generated doc-comments suppressed.
+pub mod suppressed {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(missing_docs = "generate", generated_doc = "", bounds_str_doc = "")]
+
+        #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS")]
+        pub trait ThreadSafe = Send + Sync;
+    }
+}
+
+fn test_set<T: localized::ThreadSafe + suppressed::ThreadSafe>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+    assert_eq!(localized::THREAD_SAFE_BOUNDS, suppressed::THREAD_SAFE_BOUNDS);
+}
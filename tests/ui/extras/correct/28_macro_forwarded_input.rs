@@ -0,0 +1,62 @@
+//! Checks that `trait_set!` can be invoked from `macro_rules!` with fragments
+//! forwarded via metavariables, which are passed as invisible groups.
+
+use trait_set::trait_set;
+
+macro_rules! alias {
+    ($(#[$meta:meta])* $vis:vis trait $name:ident = $first:ty $(, $rest:ty)*) => {
+        trait_set! {
+            $(#[$meta])*
+            $vis trait $name = $first $(+ $rest)*;
+        }
+    };
+}
+
+macro_rules! generic_alias {
+    ($vis:vis $name:ident<$lt:lifetime, $param:ident: $bound:path> = $item:ty) => {
+        trait_set! {
+            $vis trait $name<$lt, $param: $lt + $bound> = Iterator<Item = $item> + $lt;
+        }
+    };
+}
+
+macro_rules! tokens {
+    ($($tokens:tt)+) => {
+        trait_set! { $($tokens)+ }
+    };
+}
+
+macro_rules! options {
+    ($max:literal, $required:ty, $import:path) => {
+        trait_set! {
+            #![trait_set(max_bounds = $max)]
+            #![require($required)]
+            use $import;
+
+            pub trait Printable = Debug + Send;
+        }
+    };
+}
+
+alias!(#[doc = "Thread-safe types."] pub trait ThreadSafe = Send, Sync);
+alias!(pub(crate) trait Collection = IntoIterator<Item = _>, Clone);
+alias!(trait Private = std::fmt::Debug);
+generic_alias!(pub Refs<'a, T: Clone> = &'a T);
+tokens!(pub trait Shared = ThreadSafe + 'static;);
+options!(3, Send, std::fmt::Debug);
+
+fn check<T: ThreadSafe + Private + Printable>(_: T) {}
+fn collect<'a, I: Refs<'a, u8>>(iter: I) -> Vec<u8> {
+    iter.cloned().collect()
+}
+fn share<T: Shared>(_: T) {}
+fn len<C: Collection<u8>>(collection: C) -> usize {
+    collection.into_iter().count()
+}
+
+fn main() {
+    check(1u8);
+    assert_eq!(collect([1u8, 2].iter()), vec![1, 2]);
+    share("static");
+    assert_eq!(len(vec![1u8, 2]), 2);
+}
@@ -0,0 +1,26 @@
+//! Checks that the visibility of companion items can differ from the alias visibility.
+
+mod outer {
+    pub mod inner {
+        use trait_set::trait_set;
+
+        trait_set! {
+            #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS", helpers_vis = "pub(in crate::outer)")]
+            pub trait ThreadSafe = Send + Sync;
+            #[trait_set(bounds_str = "STATIC_BOUNDS", helpers_vis = "pub")]
+            pub(crate) trait Static = 'static + Send;
+        }
+    }
+
+    pub fn thread_safe_bounds() -> &'static str {
+        inner::THREAD_SAFE_BOUNDS
+    }
+}
+
+fn check<T: outer::inner::ThreadSafe + outer::inner::Static>(_: T) {}
+
+fn main() {
+    check(1u8);
+    assert_eq!(outer::thread_safe_bounds(), "Send + Sync");
+    assert_eq!(outer::inner::STATIC_BOUNDS, "'static + Send");
+}
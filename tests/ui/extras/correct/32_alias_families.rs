@@ -0,0 +1,41 @@
+//! Checks that alias families with alternatives in braces are expanded
+//! into one alias per alternative, also next to inner attributes of the invocation.
+
+use std::collections::HashMap;
+
+use trait_set::trait_set;
+
+trait_set! {
+    use std::io::{Read, Write};
+
+    pub trait {Read, Write}Job = Send + {Read, Write};
+    pub(crate) trait Job{Small, Large,} = Into<{u8, u64,}>;
+    trait Map{Keys, Pairs}<K> = Fn({Vec<K>, HashMap<K, K>}) -> usize;
+}
+
+trait_set! {
+    #![require(Send)]
+    #![trait_set(missing_docs = "allow")]
+
+    pub trait {Read, Write}SendJob = Send + std::io::{Read, Write};
+}
+
+fn read<T: ReadJob>(_: T) {}
+fn write<T: WriteJob>(_: T) {}
+fn read_send<T: ReadSendJob>(_: T) {}
+fn write_send<T: WriteSendJob>(_: T) {}
+fn small<T: JobSmall>(_: T) {}
+fn large<T: JobLarge>(_: T) {}
+fn keys<F: MapKeys<u8>>(_: F) {}
+fn pairs<F: MapPairs<u8>>(_: F) {}
+
+fn main() {
+    read(std::io::empty());
+    write(std::io::sink());
+    read_send(std::io::empty());
+    write_send(std::io::sink());
+    small(1u8);
+    large(1u32);
+    keys(|keys: Vec<u8>| keys.len());
+    pairs(|pairs: HashMap<u8, u8>| pairs.len());
+}
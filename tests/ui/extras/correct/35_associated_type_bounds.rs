@@ -0,0 +1,49 @@
+//! Checks that associated type bounds (`Trait<Assoc: Bound>`) are supported
+//! in alias bounds and are implied by the alias.
+
+use trait_set::trait_set;
+
+pub trait HasParent {}
+pub trait HasInnerSpec {
+    type InnerSpec;
+}
+pub trait Resource {
+    type DynamicType;
+}
+
+trait_set! {
+    #![trait_set(missing_docs = "generate")]
+    #![warn(trait_set::redundant_bounds)]
+
+    #[trait_set(bounds_str = "SPEC_BOUNDS")]
+    pub trait Spec = HasInnerSpec<InnerSpec: HasParent> + Resource<DynamicType = ()>;
+    pub trait Items<T> = IntoIterator<Item = T, IntoIter: ExactSizeIterator>;
+}
+
+struct Parent;
+impl HasParent for Parent {}
+
+struct Child;
+impl HasInnerSpec for Child {
+    type InnerSpec = Parent;
+}
+impl Resource for Child {
+    type DynamicType = ();
+}
+
+fn parent<P: HasParent>() {}
+fn spec<S: Spec>() {
+    parent::<S::InnerSpec>();
+}
+fn len<I: Items<u8>>(items: I) -> usize {
+    items.into_iter().len()
+}
+
+fn main() {
+    spec::<Child>();
+    assert_eq!(len(vec![1, 2]), 2);
+    assert_eq!(
+        SPEC_BOUNDS,
+        "HasInnerSpec<InnerSpec: HasParent> + Resource<DynamicType = ()>"
+    );
+}
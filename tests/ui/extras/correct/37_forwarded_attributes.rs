@@ -0,0 +1,29 @@
+//! Checks that `#[cfg(...)]` attributes of an alias are applied to all the generated
+//! items, and other attributes are forwarded to the trait.
+
+#![deny(missing_docs)]
+//! Crate documentation.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send)]
+
+    /// Never enabled.
+    #[cfg(any())]
+    #[trait_set(bounds_str = "DISABLED_BOUNDS")]
+    pub trait Disabled = NotDeclaredAnywhere;
+
+    /// Enabled alias.
+    #[cfg(not(any()))]
+    #[cfg_attr(not(any()), doc = "Extra doc line.")]
+    #[trait_set(bounds_str = "ENABLED_BOUNDS")]
+    pub trait Enabled = Send + Sync;
+}
+
+fn enabled<T: Enabled>(_: T) {}
+
+fn main() {
+    enabled(1u8);
+    assert_eq!(ENABLED_BOUNDS, "Send + Sync");
+}
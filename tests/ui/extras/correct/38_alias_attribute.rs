@@ -0,0 +1,31 @@
+//! Checks that `#[trait_set::alias]` declares aliases from trait items.
+
+use std::fmt::Debug;
+
+#[trait_set::alias(Send + Sync)]
+pub trait ThreadSafe {}
+
+/// Documented alias.
+#[trait_set::alias]
+#[trait_set(bounds_str = "ITEMS_BOUNDS")]
+pub trait Items<T>: ThreadSafe + Iterator<Item = T> {}
+
+#[trait_set::alias(Clone)]
+pub(crate) trait Printable<'a, T: 'a>: Debug
+where
+    T: Debug,
+{
+}
+
+fn items<T, I: Items<T>>(items: I) -> usize {
+    items.count()
+}
+fn print<'a, T: Debug + 'a, P: Printable<'a, T>>(value: P) -> String {
+    format!("{:?}", value.clone())
+}
+
+fn main() {
+    assert_eq!(items(vec![1u8, 2].into_iter()), 2);
+    assert_eq!(print::<u8, _>(1u8), "1");
+    assert_eq!(ITEMS_BOUNDS, "ThreadSafe + Iterator<Item = T>");
+}
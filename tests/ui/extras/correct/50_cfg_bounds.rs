@@ -0,0 +1,38 @@
+//! Checks that bounds under `#[cfg(...)]` are only required when the condition holds.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(std::io::Read)]
+
+    #[trait_set(bounds_str = "STORAGE_BOUNDS", dyn_alias = "DynStorage")]
+    pub trait Storage = std::io::Read
+        + #[cfg(unix)] std::io::Write
+        + #[cfg(not(unix))] Clone
+        + #[cfg(all(unix, any()))] std::io::Seek
+        + #[cfg(unix)] Send;
+}
+
+trait_set! {
+    pub trait Items<T> = Iterator<Item = T> + #[cfg(any())] Clone;
+}
+
+#[cfg(unix)]
+fn store<S: Storage>(mut storage: S) {
+    storage.write_all(b"data").unwrap();
+}
+
+fn count<I: Items<u8>>(items: I) -> usize {
+    items.count()
+}
+
+fn main() {
+    #[cfg(unix)]
+    {
+        store(std::io::empty());
+        assert_eq!(STORAGE_BOUNDS, "std::io::Read + std::io::Write + Send");
+        let _: Box<DynStorage> = Box::new(std::io::empty());
+    }
+    // `Clone` is never required, so non-cloneable iterators are accepted.
+    assert_eq!(count(vec![1u8].into_iter().map(|x| x)), 1);
+}
@@ -0,0 +1,37 @@
+//! Checks that aliases and their companion items can have restricted visibilities.
+
+mod outer {
+    pub mod inner {
+        use trait_set::trait_set;
+
+        trait_set! {
+            #![trait_set(nightly_equivalent)]
+
+            /// Visible in `outer` only.
+            #[trait_set(bounds_str = "SHARED", helpers_vis = "pub(in crate::outer)")]
+            pub(in crate::outer) trait Shared = Send + Sync;
+            pub(super) trait Local = Clone;
+            pub(self) trait Private = Copy;
+            pub(crate) trait Crate = Shared + Local;
+        }
+
+        fn check<T: Private + Crate>(_: T) {}
+
+        pub fn run() {
+            check(1u8);
+        }
+    }
+
+    fn check<T: inner::Shared + inner::Local>(_: T) -> &'static str {
+        inner::SHARED
+    }
+
+    pub fn run() {
+        assert_eq!(check(1u8), "Send + Sync");
+        inner::run();
+    }
+}
+
+fn main() {
+    outer::run();
+}
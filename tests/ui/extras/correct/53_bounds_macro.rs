@@ -0,0 +1,48 @@
+//! Checks that `bounds_macro` option generates a macro expanding to the alias bounds,
+//! usable in types and to build other aliases.
+
+use trait_set::trait_set;
+
+pub trait Marker {}
+
+impl<T> Marker for T {}
+
+mod inner {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #[trait_set(bounds_macro = "thread_safe_bounds")]
+        pub trait ThreadSafe = Send + Sync + crate::Marker;
+        #[trait_set(bounds_macro = "local_bounds")]
+        pub(crate) trait Local = Clone + std::fmt::Debug;
+        // The macro is not exported, so it doesn't clash with the one of the crate root.
+        #[trait_set(bounds_macro = "shared_bounds", helpers_vis = "pub(crate)")]
+        pub trait Printable = std::fmt::Display;
+    }
+
+    pub fn debug(value: local_bounds!(impl)) -> String {
+        format!("{:?}", value.clone())
+    }
+
+    pub fn print(value: shared_bounds!(impl)) -> String {
+        value.to_string()
+    }
+}
+
+// Exported macros are available at the crate root.
+thread_safe_bounds!(trait_set {
+    #[trait_set(bounds_str = "SHARED", bounds_macro = "shared_bounds")]
+    pub trait Shared =
+} {
+    + 'static;
+});
+
+fn share<T: Shared>(_: T) {}
+
+fn main() {
+    let value: Box<thread_safe_bounds!(dyn)> = Box::new(1u8);
+    share(value);
+    assert_eq!(SHARED, "Send + Sync + crate::Marker + 'static");
+    assert_eq!(inner::debug(1u8), "1");
+    assert_eq!(inner::print(1u8), "1");
+}
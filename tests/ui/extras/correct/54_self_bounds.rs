@@ -0,0 +1,73 @@
+//! Checks that bounds of an alias can refer to `Self`, including in generic
+//! arguments and associated types, e.g. for numeric-style aliases.
+
+use std::iter::FromIterator;
+use std::ops::{Add, Mul};
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(bounds_str = "NUMERIC", assertions)]
+    pub trait Numeric = PartialOrd<Self> + Add<Self, Output = Self> + Mul<Output = Self> + Copy;
+    pub trait Scaled<T> = Mul<T, Output = Self> + Numeric + Into<T>;
+    pub trait Collection = IntoIterator + FromIterator<<Self as IntoIterator>::Item>;
+}
+
+assert_impl_Numeric!(u8, f64);
+
+fn max_sum<T: Numeric>(a: T, b: T, c: T) -> T {
+    let sum = a + b;
+    if sum > c * c {
+        sum
+    } else {
+        c * c
+    }
+}
+
+fn scale<T: Scaled<u32>>(value: T, factor: u32) -> u32 {
+    (value * factor).into()
+}
+
+fn reversed<C: Collection>(collection: C) -> C {
+    let mut items: Vec<_> = collection.into_iter().collect();
+    items.reverse();
+    items.into_iter().collect()
+}
+
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+struct Meters(u32);
+
+impl Add for Meters {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Meters(self.0 + other.0)
+    }
+}
+
+impl Mul for Meters {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Meters(self.0 * other.0)
+    }
+}
+
+impl Mul<u32> for Meters {
+    type Output = Self;
+    fn mul(self, factor: u32) -> Self {
+        Meters(self.0 * factor)
+    }
+}
+
+impl From<Meters> for u32 {
+    fn from(meters: Meters) -> Self {
+        meters.0
+    }
+}
+
+fn main() {
+    assert_eq!(NUMERIC, "PartialOrd<Self> + Add<Self, Output = Self> + Mul<Output = Self> + Copy");
+    assert_eq!(max_sum(1u8, 2, 1), 3);
+    assert!(max_sum(1.0, 2.0, 3.0) == 9.0);
+    assert_eq!(scale(Meters(2), 3), 6);
+    assert_eq!(reversed(vec![1u8, 2, 3]), vec![3, 2, 1]);
+}
@@ -0,0 +1,68 @@
+//! Checks that parenthesized `Fn` trait sugar round-trips in alias bounds,
+//! including return types, higher-ranked lifetimes and generic parameters.
+
+use std::fmt::Debug;
+
+use trait_set::trait_set;
+
+#[derive(Debug, PartialEq)]
+pub struct Error;
+
+trait_set! {
+    #[trait_set(bounds_str = "CALLBACK", box_alias = "BoxedCallback", assertions)]
+    pub trait Callback<T> = FnMut(T) -> Result<(), Error> + Send + 'static;
+    #[trait_set(bounds_str = "HRTB_HANDLER", dyn_alias = "DynHrtbHandler")]
+    pub trait HrtbHandler = for<'a> Fn(&'a str) -> &'a str;
+    pub trait Handler<'a, T: Debug, R = ()> = Fn(&'a T, &mut Vec<T>) -> R + Sync;
+    pub trait Factory<T> = Fn() -> Box<dyn Fn(T) -> T + Send> + Clone;
+    pub trait Producer = FnOnce() -> Option<u8>;
+    pub trait Trim = Fn(&str) -> &str + Copy;
+    pub trait Visitor = for<'a, 'b> FnMut(&'a str, &'b [u8]) + ?Sized;
+    pub trait Pipeline<T> = Callback<T> + Clone;
+}
+
+fn run<T, C: Callback<T>>(mut callback: C, value: T) -> Result<(), Error> {
+    callback(value)
+}
+
+fn handle<H: HrtbHandler>(handler: H) -> usize {
+    let owned = String::from("owned");
+    handler(&owned).len()
+}
+
+fn collect<'a, T: Debug, H: Handler<'a, T, usize>>(handler: H, value: &'a T) -> usize {
+    let mut out = Vec::new();
+    handler(value, &mut out)
+}
+
+fn main() {
+    assert_eq!(CALLBACK, "FnMut(T) -> Result<(), Error> + Send + 'static");
+    assert_eq!(HRTB_HANDLER, "for<'a> Fn(&'a str) -> &'a str");
+    assert_impl_Callback!(fn(u8) -> Result<(), Error>; u8);
+
+    assert_eq!(run(|value: u8| if value > 0 { Ok(()) } else { Err(Error) }, 1), Ok(()));
+    let mut boxed: BoxedCallback<'static, u8> = Box::new(|_| Err(Error));
+    assert_eq!(boxed(1), Err(Error));
+
+    assert_eq!(handle(|s: &str| &s[1..]), 4);
+    let dynamic: &DynHrtbHandler = &|s: &str| s;
+    assert_eq!(handle(dynamic), 5);
+
+    assert_eq!(collect(|value: &u8, out: &mut Vec<u8>| { out.push(*value); out.len() }, &1), 1);
+
+    let factory = || -> Box<dyn Fn(u8) -> u8 + Send> { Box::new(|x| x + 1) };
+    fn make<T, F: Factory<T>>(factory: F) -> F { factory.clone() }
+    assert_eq!(make(factory)()(1), 2);
+
+    fn produce<P: Producer>(producer: P) -> Option<u8> { producer() }
+    assert_eq!(produce(|| Some(1)), Some(1));
+
+    fn trim<T: Trim>(trim: T) -> usize { trim(" a ").len() }
+    assert_eq!(trim(str::trim), 1);
+
+    fn visit<V: Visitor>(visitor: &mut V) { visitor("a", b"b") }
+    visit(&mut |_: &str, _: &[u8]| {});
+
+    fn pipe<T, P: Pipeline<T>>(pipeline: P, value: T) -> Result<(), Error> { run(pipeline.clone(), value) }
+    assert_eq!(pipe(|_: u8| Ok(()), 1), Ok(()));
+}
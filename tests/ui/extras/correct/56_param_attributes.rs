@@ -0,0 +1,64 @@
+//! Checks that attributes of generic parameters are applied to the generated trait
+//! and blanket impl consistently, and that `#[cfg(...)]` removes the parameter.
+
+#![deny(non_camel_case_types)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Convert<#[cfg(any())] T, #[cfg(all())] U, #[cfg(any())] 'a, #[cfg(all())] 'b> =
+        From<U> + AsRef<&'b str>;
+    pub trait Bytes<#[cfg(all())] const N: usize, #[cfg(any())] const M: usize> =
+        Into<[u8; N]> + #[cfg(any())] AsMut<[u8; M]>;
+    pub trait Lowercase<#[allow(non_camel_case_types)] item> = Iterator<Item = item>;
+    #[trait_set(bounds_str = "MAYBE_LENGTH")]
+    pub trait MaybeLength<#[cfg(debug_assertions)] T> =
+        Clone + #[cfg(debug_assertions)] AsRef<[T]> + #[cfg(not(debug_assertions))] AsRef<[u8]>;
+}
+
+struct Name(&'static str);
+
+impl From<u8> for Name {
+    fn from(_: u8) -> Self {
+        Name("byte")
+    }
+}
+
+impl AsRef<&'static str> for Name {
+    fn as_ref(&self) -> &&'static str {
+        &self.0
+    }
+}
+
+fn convert<'b, C: Convert<'b, u8>>(value: u8) -> &'b str {
+    C::from(value).as_ref()
+}
+
+fn first<B: Bytes<2>>(bytes: B) -> u8 {
+    bytes.into()[0]
+}
+
+#[allow(non_camel_case_types)]
+fn count<item, L: Lowercase<item>>(iter: L) -> usize {
+    iter.count()
+}
+
+#[cfg(debug_assertions)]
+fn length<L: MaybeLength<u16>>(value: L) -> usize {
+    value.as_ref().len()
+}
+
+#[cfg(not(debug_assertions))]
+fn length<L: MaybeLength>(value: L) -> usize {
+    value.as_ref().len()
+}
+
+fn main() {
+    assert_eq!(convert::<Name>(1), "byte");
+    assert_eq!(first([1u8, 2]), 1);
+    assert_eq!(count(vec![1u8, 2].into_iter()), 2);
+    #[cfg(debug_assertions)]
+    assert_eq!(length(vec![1u16, 2]), 2);
+    #[cfg(debug_assertions)]
+    assert_eq!(MAYBE_LENGTH, "Clone + AsRef<[T]>");
+}
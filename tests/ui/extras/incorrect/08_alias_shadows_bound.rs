@@ -0,0 +1,32 @@
+//! Checks that an alias referring to itself in its bounds is rejected, and that
+//! an alias with the same name as its bound is reported by `shadowed_bound` lint if enabled.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Iterator = Iterator<Item = u8> + Send;
+}
+
+mod denied {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![deny(trait_set::shadowed_bound)]
+
+        pub trait Iterator = std::iter::Iterator<Item = u8> + Send;
+    }
+}
+
+mod warned {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![warn(trait_set::shadowed_bound)]
+
+        pub trait Error = std::error::Error + Send + Sync + 'static;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! Checks that a likely misspelled reference to another alias is reported
+//! if `possible_typo` lint is enabled.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![warn(trait_set::possible_typo)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait ThreadSafeIterator<T> = TreadSafe + Iterator<Item = T>;
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Checks that a `pub` alias built from a non-`pub` alias is reported
+//! if `private_component` lint is enabled.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![warn(trait_set::private_component)]
+
+    pub(crate) trait ThreadSafe = Send + Sync;
+    trait StaticDebug = 'static + std::fmt::Debug;
+    pub trait ThreadSafeIterator<T> = ThreadSafe + StaticDebug + Iterator<Item = T>;
+    pub(crate) trait CrateIterator<T> = ThreadSafe + Iterator<Item = T>;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! Checks that glob imports inside the invocation are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    use std::fmt::*;
+
+    pub trait Printable = Debug + Display;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//! Checks that aliases using forbidden traits are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(forbid(Copy))]
+
+    pub trait Cloneable = Clone + Send;
+    pub trait Copyable = Clone + std::marker::Copy;
+}
+
+fn main() {}
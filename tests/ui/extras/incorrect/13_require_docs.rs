@@ -0,0 +1,15 @@
+//! Checks that undocumented `pub` aliases are rejected when docs are required.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(require_docs)]
+
+    /// Documented alias.
+    pub trait ThreadSafe = Send + Sync;
+    // Non-public aliases don't need to be documented.
+    pub(crate) trait CrateIterator<T> = ThreadSafe + Iterator<Item = T>;
+    pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+}
+
+fn main() {}
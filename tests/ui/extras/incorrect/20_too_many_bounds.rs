@@ -0,0 +1,16 @@
+//! Checks that aliases exceeding `max_bounds` are reported.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(max_bounds = 3)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait Printable = std::fmt::Debug + std::fmt::Display;
+    pub trait Value = ThreadSafe + Clone;
+    pub trait PrintableValue = ThreadSafe + Printable + 'static;
+}
+
+fn main() {}
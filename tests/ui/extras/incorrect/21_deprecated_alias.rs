@@ -0,0 +1,24 @@
+//! Checks that deprecation of an alias is applied to its companion items,
+//! but doesn't fire inside the generated code.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send)]
+
+    #[deprecated(note = "use `Sync` directly")]
+    #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS", bounds_macro = "thread_safe_bounds")]
+    pub trait ThreadSafe = Send + Sync;
+    #[deprecated]
+    pub trait GenericIterator<T> = Send + Iterator<Item = T>;
+}
+
+fn test_set<T: ThreadSafe>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+    let _ = THREAD_SAFE_BOUNDS;
+    let _: Box<thread_safe_bounds!(dyn)> = Box::new(10u8);
+}
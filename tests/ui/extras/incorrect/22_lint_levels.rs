@@ -0,0 +1,25 @@
+//! Checks that lints of the macro can be denied inside the invocation,
+//! and that only known lints of the macro are accepted.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![deny(trait_set::possible_typo)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait ThreadSafeIterator<T> = TreadSafe + Iterator<Item = T>;
+}
+
+trait_set! {
+    #![allow(trait_set::unknown_lint)]
+
+    pub trait Sendable = Send;
+}
+
+trait_set! {
+    #![allow(dead_code)]
+
+    pub trait Syncable = Sync;
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Checks that duplicate generic parameters of an alias are reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Pair<T, T> = Iterator<Item = (T, T)>;
+}
+
+trait_set! {
+    pub trait Borrowed<'a, T: 'a, 'a> = Iterator<Item = &'a T>;
+}
+
+trait_set! {
+    pub trait Bytes<T, const T: usize> = AsRef<[T]>;
+}
+
+fn main() {}
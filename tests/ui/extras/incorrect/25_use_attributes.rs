@@ -0,0 +1,12 @@
+//! Checks that attributes on `use` declarations inside the invocation are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[allow(unused_imports)]
+    use std::fmt::Debug;
+
+    pub trait Printable = Debug;
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Checks that companion items can be kept private while the alias is public.
+
+mod aliases {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #[trait_set(bounds_str = "THREAD_SAFE_BOUNDS", helpers_vis = "")]
+        pub trait ThreadSafe = Send + Sync;
+    }
+}
+
+fn check<T: aliases::ThreadSafe>(_: T) {}
+
+fn main() {
+    check(1u8);
+    assert_eq!(aliases::THREAD_SAFE_BOUNDS, "Send + Sync");
+}
@@ -0,0 +1,23 @@
+//! Checks that redundant bounds are reported once `redundant_bounds` lint is enabled.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![deny(trait_set::redundant_bounds)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait Value = Copy + Clone + std::fmt::Debug;
+    pub trait Shared = ThreadSafe + Send + 'static;
+    pub trait Repeated = Send + Send;
+    pub trait Sorted = Ord + PartialEq;
+    pub trait Failure = std::error::Error + std::fmt::Display;
+    pub trait Items<T: Sized> = Iterator<Item = T>;
+    pub trait Unsized<T: ?Sized> = AsRef<T>;
+}
+
+// The lint is allowed by default.
+trait_set! {
+    pub trait Implicit = Copy + Clone;
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Checks that misuses of inline aliases are reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(inline)]
+    trait Items<T> = Iterator<Item = T>;
+}
+
+trait_set! {
+    #[trait_set(inline, bounds_str = "BOUNDS")]
+    trait Sendable = Send;
+}
+
+trait_set! {
+    #[trait_set(inline)]
+    trait First = Second + Send;
+    #[trait_set(inline)]
+    trait Second = First + Sync;
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+//! Checks that `on_unimplemented` option hints at the alias bounds,
+//! and that the attribute written by hand takes precedence.
+
+use std::rc::Rc;
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(on_unimplemented)]
+
+    pub trait ThreadSafe = Send + Sync;
+    #[diagnostic::on_unimplemented(message = "`{Self}` can't be shared")]
+    pub trait Shared = Sync;
+}
+
+fn thread_safe<T: ThreadSafe>(_: T) {}
+fn shared<T: Shared>(_: T) {}
+
+fn main() {
+    thread_safe(Rc::new(1u8));
+    shared(Rc::new(1u8));
+}
@@ -0,0 +1,10 @@
+//! Checks that `impl_param` option can't repeat a generic parameter of the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(impl_param = "T")]
+    pub trait Items<T> = Iterator<Item = T>;
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! Checks that supertrait syntax instead of `=` is reported with a dedicated error.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe: Send + Sync;
+}
+
+trait_set! {
+    pub trait Items<T>: Iterator<Item = T> {}
+}
+
+fn main() {}
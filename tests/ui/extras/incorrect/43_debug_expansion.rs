@@ -0,0 +1,16 @@
+//! Checks that `debug` option reports the expansion of the alias.
+//! The warning is denied here, so that its text is checked.
+
+#![deny(deprecated)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send)]
+
+    /// Thread-safe iterators.
+    #[trait_set(debug, bounds_str = "ITEMS")]
+    pub trait Items<T> = Iterator<Item = T> + Send;
+}
+
+fn main() {}
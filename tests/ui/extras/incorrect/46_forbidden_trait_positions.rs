@@ -0,0 +1,34 @@
+//! Checks that forbidden traits are rejected in the where clause, in the bounds
+//! of generic parameters and in the bounds of associated types.
+
+mod where_clause {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(forbid(Copy))]
+
+        pub trait Copyable = Clone where Self: Copy;
+    }
+}
+
+mod generic_param {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(forbid(Copy))]
+
+        pub trait Items<T: Copy> = Iterator<Item = T>;
+    }
+}
+
+mod associated_type {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(forbid(Copy))]
+
+        pub trait CopyItems = Iterator<Item: std::marker::Copy>;
+    }
+}
+
+fn main() {}
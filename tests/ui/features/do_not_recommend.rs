@@ -0,0 +1,21 @@
+//! Checks that errors name the alias rather than go through the blanket impl
+//! with `do-not-recommend` feature.
+
+use std::rc::Rc;
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(on_unimplemented)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait Items<T> = Iterator<Item = T> + Send;
+}
+
+fn thread_safe<T: ThreadSafe>(_: T) {}
+fn items<T, I: Items<T>>(_: I) {}
+
+fn main() {
+    thread_safe(Rc::new(1u8));
+    items::<u8, _>(vec![Rc::new(1u8)].into_iter());
+}
@@ -0,0 +1,18 @@
+//! Checks that `use` declarations and options relying on `extras` feature
+//! are rejected without it.
+
+use trait_set::trait_set;
+
+trait_set! {
+    use std::fmt::Debug;
+
+    pub trait Printable = Debug + Send;
+}
+
+trait_set! {
+    #![trait_set(on_unimplemented)]
+
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn main() {}
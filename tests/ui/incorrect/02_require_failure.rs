@@ -0,0 +1,12 @@
+//! Checks that an alias not implying the invocation-level requirement is reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![require(Send + Sync)]
+
+    pub trait ThreadSafe = Send + Sync;
+    pub trait BytesIterator = Iterator<Item = u8>;
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+//! Checks that unknown inner attributes are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![requires(Send)]
+
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn main() {}
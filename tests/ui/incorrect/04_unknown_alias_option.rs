@@ -0,0 +1,10 @@
+//! Checks that unknown per-alias options are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(allow_everything)]
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn main() {}
@@ -0,0 +1,35 @@
+//! Checks that undocumented aliases are reported by `missing_docs` lint by default.
+
+#![deny(missing_docs)]
+
+/// Aliases with generated doc-comments.
+pub mod generated {
+    use trait_set::trait_set;
+
+    trait_set! {
+        #![trait_set(missing_docs = "generate")]
+
+        pub trait ThreadSafe = Send + Sync;
+        pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+        /// Documented aliases are kept as is.
+        pub trait StaticDebug = 'static + std::fmt::Debug;
+    }
+}
+
+/// Aliases with missing docs allowed.
+pub mod allowed {
+    use trait_set::trait_set;
+
+    trait_set! {
+        
+
+        pub trait ThreadSafe = Send + Sync;
+        pub trait ThreadSafeIterator<T> = ThreadSafe + Iterator<Item = T>;
+    }
+}
+
+fn test_set<T: generated::ThreadSafe + allowed::ThreadSafe>(_arg: T) {}
+
+fn main() {
+    test_set(10u8);
+}
@@ -0,0 +1,11 @@
+//! Checks that unknown `missing_docs` values are rejected.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #![trait_set(missing_docs = "ignore")]
+
+    pub trait ThreadSafe = Send + Sync;
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+//! Checks that `impl` keyword in bounds is reported with a hint.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Source = impl Iterator<Item = u8> + Send;
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! Checks that `#[trait_set::require]` reports types not satisfying the aliases.
+
+use std::cell::RefCell;
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+}
+
+#[trait_set::require(ThreadSafe)]
+struct Cache {
+    _inner: RefCell<u8>,
+}
+
+fn main() {}
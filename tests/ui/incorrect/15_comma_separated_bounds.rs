@@ -0,0 +1,9 @@
+//! Checks that comma-separated bounds are reported with a hint.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait GenericIterator<T> = Iterator<Item = T>, Send;
+}
+
+fn main() {}
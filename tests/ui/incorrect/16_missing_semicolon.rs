@@ -0,0 +1,11 @@
+//! Checks that a missing semicolon between aliases is reported at the end of the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+    pub trait BytesIterator = Iterator<Item = u8>
+    pub trait StaticDebug = 'static + std::fmt::Debug;
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+//! Checks that items other than aliases are reported with a hint.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+
+    pub fn helper() {}
+}
+
+trait_set! {
+    pub trait ThreadSafe2 = Send + Sync;
+
+    use std::fmt::Debug;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+//! Checks that attributes after the last alias are reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+
+    /// Documentation of an alias that was removed.
+    #[doc = "More of it."]
+}
+
+fn main() {}
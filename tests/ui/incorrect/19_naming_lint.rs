@@ -0,0 +1,12 @@
+//! Checks that naming lints on aliases point at the alias name.
+
+#![deny(non_camel_case_types)]
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync;
+    pub trait generic_iterator<T> = ThreadSafe + Iterator<Item = T>;
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+//! Checks that where clauses both before `=` and after the bounds
+//! are reported with a dedicated error.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Printable<T> where T: Copy = AsRef<T> where T: Send;
+}
+
+fn main() {}
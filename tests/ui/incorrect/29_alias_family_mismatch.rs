@@ -0,0 +1,10 @@
+//! Checks that braces of an alias family with different numbers
+//! of alternatives are reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait {Read, Write}Job = Send + std::io::{Read, Write, Seek};
+}
+
+fn main() {}
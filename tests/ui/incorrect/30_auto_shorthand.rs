@@ -0,0 +1,23 @@
+//! Checks that misuses of `@auto` shorthand are reported.
+
+use std::rc::Rc;
+
+trait_set::trait_set! {
+    pub trait Service = Clone + @auto;
+}
+
+trait_set::trait_set! {
+    pub trait Unknown = Clone + @all;
+}
+
+trait_set::trait_set! {
+    #![trait_set(auto_traits(Send, Copy))]
+
+    pub trait Copyable = @auto;
+}
+
+fn service<T: Service>(_: T) {}
+
+fn main() {
+    service(Rc::new(1u8));
+}
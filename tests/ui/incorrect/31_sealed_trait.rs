@@ -0,0 +1,16 @@
+//! Checks that sealed traits can't be implemented for other types,
+//! and that `#[trait_set::seal]` is only applied to traits.
+
+mod keys {
+    #[trait_set::seal(u8)]
+    pub trait Key {}
+
+    impl Key for u8 {}
+}
+
+impl keys::Key for u16 {}
+
+#[trait_set::seal(u8)]
+pub struct NotATrait;
+
+fn main() {}
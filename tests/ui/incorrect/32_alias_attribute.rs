@@ -0,0 +1,15 @@
+//! Checks that traits that can't be aliases are reported
+//! by `#[trait_set::alias]`.
+
+#[trait_set::alias(Send + Sync)]
+pub trait WithItems {
+    fn check(&self);
+}
+
+#[trait_set::alias(Send)]
+pub auto trait Auto {}
+
+#[trait_set::alias]
+pub trait NoBounds {}
+
+fn main() {}
@@ -0,0 +1,23 @@
+//! Checks that sealed aliases are not implemented for types not listed in `impl_Alias!`,
+//! and can't have generic parameters.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(sealed)]
+    pub trait Key = Clone + Send;
+}
+
+trait_set! {
+    #[trait_set(sealed)]
+    pub trait Items<T> = Iterator<Item = T>;
+}
+
+impl_Key!(u8);
+
+fn key<K: Key>(_: K) {}
+
+fn main() {
+    key(1u8);
+    key(1u16);
+}
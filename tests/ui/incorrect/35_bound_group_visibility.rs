@@ -0,0 +1,10 @@
+//! Checks that bound groups can't have a visibility, since no items are generated for them.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub set Common = Send + Sync;
+    pub trait Worker = Common + FnMut();
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! Checks that `assert_object_safe` option reports aliases that can't be used
+//! as trait objects at the alias declaration.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(assert_object_safe)]
+    pub trait Value = Clone + Send;
+    #[trait_set(assert_object_safe)]
+    pub trait Handler = Fn(u8) -> u8 + Send;
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+//! Checks that `assert_impl_Alias!` macros generated by `assertions` option
+//! report types not implementing the alias.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(assertions)]
+    pub trait ThreadSafe = Send + Sync;
+}
+
+assert_impl_ThreadSafe!(u8, std::rc::Rc<u8>);
+
+fn main() {}
@@ -0,0 +1,26 @@
+//! Checks that `hrtb_alias` option is rejected for aliases without lifetime parameters,
+//! with bounded ones or with ones used outside of the alias bounds.
+
+use trait_set::trait_set;
+
+trait_set! {
+    #[trait_set(hrtb_alias = "AnyItems")]
+    pub trait Items<T> = Iterator<Item = T>;
+}
+
+trait_set! {
+    #[trait_set(hrtb_alias = "AnyRefs")]
+    pub trait Refs<'a, 'b: 'a> = Iterator<Item = &'a &'b u8>;
+}
+
+trait_set! {
+    #[trait_set(hrtb_alias = "AnyBorrowed")]
+    pub trait Borrowed<'a, T: 'a> = AsRef<&'a T>;
+}
+
+trait_set! {
+    #[trait_set(hrtb_alias = "AnyLookup")]
+    pub trait Lookup<'a, K> = Fn(&'a str) -> K where K: From<&'a str>;
+}
+
+fn main() {}
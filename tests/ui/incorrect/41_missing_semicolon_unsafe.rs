@@ -0,0 +1,18 @@
+//! Checks that a missing semicolon before an `unsafe` alias is reported at the end
+//! of the previous alias.
+
+use trait_set::trait_set;
+
+pub unsafe trait Pod {}
+
+trait_set! {
+    pub trait ThreadSafe = Send + Sync
+    pub unsafe trait PodSafe = Pod + ThreadSafe;
+}
+
+trait_set! {
+    trait Shared = Send + Sync + 'static
+    unsafe trait SharedPod = Pod + Shared;
+}
+
+fn main() {}
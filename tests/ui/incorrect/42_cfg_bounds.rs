@@ -0,0 +1,18 @@
+//! Checks that only `#[cfg(...)]` attributes are allowed on alias bounds,
+//! and that bounds under `#[cfg(...)]` are checked when the condition holds.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Storage = std::io::Read + #[doc = "Writer"] std::io::Write;
+}
+
+trait_set! {
+    pub trait Shared = Clone + #[cfg(all())] Send;
+}
+
+fn share<T: Shared>(_: T) {}
+
+fn main() {
+    share(std::rc::Rc::new(1u8));
+}
@@ -0,0 +1,14 @@
+//! Checks that a visibility restricted to a module that is not an ancestor
+//! of the invocation is reported at the alias.
+
+mod other {}
+
+mod inner {
+    use trait_set::trait_set;
+
+    trait_set! {
+        pub(in crate::other) trait Shared = Send + Sync;
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+//! Checks that closures not matching the `Fn` trait sugar of an alias are reported.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Callback<T> = FnMut(T) -> Result<(), String> + Send + 'static;
+}
+
+fn run<T, C: Callback<T>>(mut callback: C, value: T) -> Result<(), String> {
+    callback(value)
+}
+
+fn main() {
+    let _ = run(|value: u8| value, 1);
+}
@@ -0,0 +1,13 @@
+//! Checks that `_` outside of the alias bounds is reported instead of being passed through.
+
+use trait_set::trait_set;
+
+trait_set! {
+    pub trait Convert<T: Into<_>> = From<T>;
+}
+
+trait_set! {
+    pub trait Collect<I> = FromIterator<I> where I: IntoIterator<Item = _>;
+}
+
+fn main() {}